@@ -0,0 +1,84 @@
+//! Compares the day01 similarity score computed with a `HashMap` of counts against the
+//! allocation-free merge of sorted columns. Run with `cargo bench --bench day01`.
+
+use advent_of_code::days::y2024::day01::{similarity_counts, similarity_sorted};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const ROUNDS: usize = 11;
+
+/// Two columns of `len` values below `max`, from a fixed-seed generator.
+fn columns(len: usize, max: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut state = 0x2024u64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize % max
+    };
+    let lhs = (0..len).map(|_| next()).collect();
+    let rhs = (0..len).map(|_| next()).collect();
+    (lhs, rhs)
+}
+
+/// The median time of `ROUNDS` runs of `f`, each given a fresh copy of the columns.
+fn time(lhs: &[usize], rhs: &[usize], f: impl Fn(Vec<usize>, Vec<usize>) -> usize) -> Duration {
+    let mut times = (0..ROUNDS)
+        .map(|_| {
+            let (lhs, rhs) = (lhs.to_vec(), rhs.to_vec());
+            let start = Instant::now();
+            black_box(f(black_box(lhs), black_box(rhs)));
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    times.sort();
+    times[ROUNDS / 2]
+}
+
+fn main() {
+    println!(
+        "{:>9}  {:>9}  {:>12}  {:>12}  {:>12}",
+        "lines", "values", "hashmap", "sort+merge", "merge only"
+    );
+    for (len, max) in [
+        (1_000, 100_000),
+        (100_000, 100_000),
+        (1_000_000, 1_000),
+        (1_000_000, 100_000),
+        (1_000_000, usize::MAX),
+    ] {
+        let (lhs, rhs) = columns(len, max);
+        let (mut sorted_lhs, mut sorted_rhs) = (lhs.clone(), rhs.clone());
+        sorted_lhs.sort_unstable();
+        sorted_rhs.sort_unstable();
+        assert_eq!(
+            similarity_counts(&lhs, &rhs),
+            similarity_sorted(&sorted_lhs, &sorted_rhs)
+        );
+
+        let hashmap = time(&lhs, &rhs, |lhs, rhs| similarity_counts(&lhs, &rhs));
+        let sort_merge = time(&lhs, &rhs, |mut lhs, mut rhs| {
+            lhs.sort_unstable();
+            rhs.sort_unstable();
+            similarity_sorted(&lhs, &rhs)
+        });
+        // The parts share part 1's sort of both columns, so only the merge is extra.
+        let merge = time(&sorted_lhs, &sorted_rhs, |lhs, rhs| {
+            similarity_sorted(&lhs, &rhs)
+        });
+        println!(
+            "{:>9}  {:>9}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+            len,
+            if max == usize::MAX {
+                "any".to_owned()
+            } else {
+                max.to_string()
+            },
+            hashmap,
+            sort_merge,
+            merge
+        );
+    }
+}
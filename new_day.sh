@@ -1,30 +1,52 @@
 #!/bin/zsh
 
+# usage: new_day.sh [day [year]], defaulting to today
+
 if [[ -z $1 ]] then
   DATE=$(date -j +'%Y-%m-%d')
-else
+elif [[ -z $2 ]] then
   DATE=$(date -j -f '%d' $1 +'%Y-%m-%d')
+else
+  DATE=$(printf '%s-12-%02d' $2 $1)
 fi
 
 echo "Checking date $DATE"
 
+YEAR=$(date -j -f '%Y-%m-%d' $DATE +'%Y')
 FILENAME="day$(date -j -f '%Y-%m-%d' $DATE +'%d')"
+YEAR_DIR="src/days/y$YEAR"
+DATA_DIR="$(cargo run --quiet --bin aoc -- config get data_dir)/$YEAR"
+COOKIE_FILE=$(cargo run --quiet --bin aoc -- config get cookie_file)
 
-RS_FILE="src/bin/$FILENAME.rs"
-if [[ -f $RS_FILE ]]; then
-    echo "$RS_FILE already exists"
-else
-    echo "creating $RS_FILE"
-    cp src/bin/day_template.rs $RS_FILE
+if [[ -z $AOC_COOKIE && -n $COOKIE_FILE && -f $COOKIE_FILE ]]; then
+  AOC_COOKIE=$(<$COOKIE_FILE)
+fi
+
+mkdir -p $YEAR_DIR $DATA_DIR
+
+for TEMPLATE RS_FILE in src/days/day_template.rs "$YEAR_DIR/$FILENAME.rs" \
+                        src/bin/day_template.rs "src/bin/y${YEAR}_$FILENAME.rs"; do
+  if [[ -f $RS_FILE ]]; then
+      echo "$RS_FILE already exists"
+  else
+      echo "creating $RS_FILE"
+      cp $TEMPLATE $RS_FILE
+  fi
+done
+
+if [[ ! -f $YEAR_DIR/mod.rs ]]; then
+    echo "remember to create $YEAR_DIR/mod.rs with its DAYS, and add \`pub mod y$YEAR;\` and y$YEAR::DAYS to YEARS in src/days/mod.rs"
+elif ! grep -q "pub mod $FILENAME;" $YEAR_DIR/mod.rs; then
+    echo "remember to add \`pub mod $FILENAME;\` and its entry in DAYS to $YEAR_DIR/mod.rs"
 fi
 
-TXT_FILE="data/$FILENAME.txt"
+TXT_FILE="$DATA_DIR/$FILENAME.txt"
 if [[ -f $TXT_FILE ]]; then
     echo "$TXT_FILE already exists"
 elif [[ -z $AOC_COOKIE ]]; then
-    echo "AOC_COOKIE not set - creating empty $TXT_FILE"
+    echo "AOC_COOKIE not set and no cookie_file configured - creating empty $TXT_FILE"
     touch $TXT_FILE
 else
     echo "downloading $TXT_FILE"
-    curl -H "cookie: $AOC_COOKIE" "https://adventofcode.com/$(date -j -f '%Y-%m-%d' $DATE +'%Y')/day/$(date -j -f '%Y-%m-%d' $DATE +'%-d')/input" -o $TXT_FILE
+    curl -H "cookie: $AOC_COOKIE" "https://adventofcode.com/$YEAR/day/$(date -j -f '%Y-%m-%d' $DATE +'%-d')/input" -o $TXT_FILE
 fi
@@ -0,0 +1,252 @@
+use anyhow::Result;
+use std::{
+    cell::RefCell,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Emits a snapshot of the simulation running on this thread. `render` is only called when
+/// frames are being recorded and this call falls on the recording stride.
+pub fn frame(render: impl FnOnce() -> String) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            recorder.push(render);
+        }
+    });
+}
+
+/// Collects the frames emitted by a solution.
+pub struct Recorder {
+    every: usize,
+    limit: usize,
+    calls: usize,
+    frames: Vec<String>,
+}
+
+impl Recorder {
+    /// Keeps every `every`th frame, stopping once `limit` frames have been kept.
+    pub fn new(every: usize, limit: usize) -> Self {
+        Recorder {
+            every: every.max(1),
+            limit,
+            calls: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Runs `f` on this thread, returning its result along with the frames it emitted.
+    pub fn record<T>(self, f: impl FnOnce() -> T) -> (T, Vec<String>) {
+        RECORDER.with(|recorder| *recorder.borrow_mut() = Some(self));
+        let result = f();
+        let recorder = RECORDER.with(|recorder| recorder.borrow_mut().take());
+        (result, recorder.map(|r| r.frames).unwrap_or_default())
+    }
+
+    fn push(&mut self, render: impl FnOnce() -> String) {
+        if self.calls.is_multiple_of(self.every) && self.frames.len() < self.limit {
+            self.frames.push(render());
+        }
+        self.calls += 1;
+    }
+}
+
+/// Writes every frame to `out` for non-interactive review.
+pub fn dump(frames: &[String], mut out: impl Write) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(out, "frame {}/{}", i + 1, frames.len())?;
+        writeln!(out, "{}\n", frame)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Toggle,
+    Step(isize),
+    Faster,
+    Slower,
+    Jump(usize),
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Command> {
+        match line.trim() {
+            "" | "p" => Some(Command::Toggle),
+            "n" => Some(Command::Step(1)),
+            "b" => Some(Command::Step(-1)),
+            "+" => Some(Command::Faster),
+            "-" => Some(Command::Slower),
+            "q" => Some(Command::Quit),
+            other => other
+                .strip_prefix('g')
+                .and_then(|n| n.trim().parse().ok())
+                .map(Command::Jump),
+        }
+    }
+}
+
+struct Player {
+    frame: usize,
+    len: usize,
+    playing: bool,
+    fps: f64,
+}
+
+impl Player {
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Toggle => self.playing = !self.playing,
+            Command::Step(n) => {
+                self.playing = false;
+                self.frame = self
+                    .frame
+                    .saturating_add_signed(n)
+                    .min(self.len.saturating_sub(1));
+            }
+            Command::Faster => self.fps = (self.fps * 2.0).min(240.0),
+            Command::Slower => self.fps = (self.fps / 2.0).max(0.25),
+            Command::Jump(n) => self.frame = n.clamp(1, self.len.max(1)) - 1,
+            Command::Quit => {}
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.frame + 1 < self.len {
+            self.frame += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+}
+
+/// Plays `frames` in the terminal. Commands are read a line at a time from stdin:
+/// enter or `p` toggles play/pause, `n`/`b` step forward/back, `+`/`-` change speed,
+/// `g N` jumps to frame N and `q` quits.
+pub fn play(frames: &[String], fps: f64) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(command) = Command::parse(&line) {
+                if tx.send(command).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let mut player = Player {
+        frame: 0,
+        len: frames.len(),
+        playing: true,
+        fps,
+    };
+    loop {
+        draw(frames, &player)?;
+        let command = if player.playing {
+            rx.recv_timeout(Duration::from_secs_f64(1.0 / player.fps))
+        } else {
+            rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+        match command {
+            Ok(Command::Quit) | Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            Ok(command) => player.apply(command),
+            Err(RecvTimeoutError::Timeout) => player.advance(),
+        }
+    }
+}
+
+fn draw(frames: &[String], player: &Player) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    write!(stdout, "\x1b[2J\x1b[H")?;
+    if let Some(frame) = frames.get(player.frame) {
+        writeln!(stdout, "{}", frame)?;
+    }
+    writeln!(
+        stdout,
+        "\nframe {}/{}  {} at {} fps  [enter] play/pause  n/b step  +/- speed  g N jump  q quit",
+        player.frame + 1,
+        frames.len(),
+        if player.playing { "playing" } else { "paused" },
+        player.fps
+    )?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simulate(steps: usize) -> usize {
+        for i in 0..steps {
+            frame(|| i.to_string());
+        }
+        steps
+    }
+
+    #[test]
+    fn records_every_nth_frame() {
+        let (result, frames) = Recorder::new(3, 100).record(|| simulate(10));
+        assert_eq!(result, 10);
+        assert_eq!(frames, vec!["0", "3", "6", "9"]);
+    }
+
+    #[test]
+    fn stops_at_limit() {
+        let (_, frames) = Recorder::new(1, 2).record(|| simulate(10));
+        assert_eq!(frames, vec!["0", "1"]);
+    }
+
+    #[test]
+    fn frames_outside_recording_are_ignored() {
+        simulate(3);
+        let (_, frames) = Recorder::new(1, 10).record(|| 0);
+        assert!(frames.is_empty());
+    }
+
+    #[test]
+    fn dump_format() {
+        let mut out = Vec::new();
+        dump(&["#.".to_owned(), ".#".to_owned()], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "frame 1/2\n#.\n\nframe 2/2\n.#\n\n"
+        );
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(Command::parse(""), Some(Command::Toggle));
+        assert_eq!(Command::parse("g 12"), Some(Command::Jump(12)));
+        assert_eq!(Command::parse("b"), Some(Command::Step(-1)));
+        assert_eq!(Command::parse("x"), None);
+    }
+
+    #[test]
+    fn player_controls() {
+        let mut player = Player {
+            frame: 0,
+            len: 5,
+            playing: true,
+            fps: 10.0,
+        };
+        player.apply(Command::Step(1));
+        assert_eq!((player.frame, player.playing), (1, false));
+        player.apply(Command::Step(-3));
+        assert_eq!(player.frame, 0);
+        player.apply(Command::Jump(9));
+        assert_eq!(player.frame, 4);
+        player.apply(Command::Faster);
+        assert_eq!(player.fps, 20.0);
+        player.apply(Command::Toggle);
+        player.advance();
+        assert_eq!((player.frame, player.playing), (4, false));
+    }
+}
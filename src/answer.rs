@@ -0,0 +1,114 @@
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A puzzle answer, keeping the type the solution produced it as.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// Serialized as a decimal string so that no precision is lost in JSON.
+    #[serde(with = "decimal")]
+    BigInt(BigInt),
+    String(String),
+}
+
+impl Answer {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::BigInt(_) => "bigint",
+            Answer::String(_) => "string",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Signed(n) => n.fmt(f),
+            Answer::BigInt(n) => n.fmt(f),
+            Answer::String(s) => s.fmt(f),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n as $target)
+            }
+        })*
+    };
+}
+
+from_int!(Unsigned, u64, u8, u16, u32, u64, usize);
+from_int!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        Answer::BigInt(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_owned())
+    }
+}
+
+mod decimal {
+    use num_bigint::BigInt;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(n: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(n)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigInt, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(answer: Answer, json: &str) {
+        assert_eq!(serde_json::to_string(&answer).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Answer>(json).unwrap(), answer);
+    }
+
+    #[test]
+    fn serde() {
+        round_trip(
+            Answer::from(1831usize),
+            r#"{"type":"unsigned","value":1831}"#,
+        );
+        round_trip(Answer::from(-7isize), r#"{"type":"signed","value":-7}"#);
+        round_trip(
+            Answer::from(BigInt::from(u64::MAX) * 10),
+            r#"{"type":"bigint","value":"184467440737095516150"}"#,
+        );
+        round_trip(Answer::from("abc"), r#"{"type":"string","value":"abc"}"#);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from(-42i32).to_string(), "-42");
+        assert_eq!(Answer::from("co,de").to_string(), "co,de");
+    }
+}
@@ -0,0 +1,362 @@
+use advent_of_code::{
+    animation::{self, Recorder},
+    config::{self, Config, LEGACY_DATA_DIR},
+    day_name,
+    days::{self, Day},
+    image::{self, Image},
+    migrate, options, read_input,
+    report::{self, Format},
+    runner::{self, Outcome},
+    serve,
+    verify::{Answers, Verdict, ANSWERS_FILE, LOCAL_ANSWERS_FILE},
+    watch,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+const USAGE: &str =
+    "usage: aoc [--year YYYY] [--data-dir DIR] [--cookie-file FILE] <command>, where command is one of
+  aoc run (--all | <day>...) [--jobs N] [--timeout SECS] [--iterations N] [--no-cache] [--format table|json]
+      run days in parallel and print a summary table, or one JSON object per part
+  aoc verify (--all | <day>...) [--record] [run options]
+      check answers against the slow salted hashes in answers.json, or record them there
+  aoc animate <day> [--part N] [--every N] [--max-frames N] [--fps N] [--dump FILE]
+      play the frames a part emits in the terminal, or write them all to FILE
+  aoc show <day> [--option [value]]...
+      print a day's visualization of its input, passing it any options the day takes
+  aoc image <day> -o FILE [--scale N]
+      draw a day as a PPM, PNG or SVG image, chosen by FILE's extension
+  aoc serve [--port N]
+      serve a dashboard of answers and visualizations on http://127.0.0.1:8024/
+  aoc watch <day>
+      re-run a day's tests and solution whenever its source or input changes
+  aoc migrate-inputs
+      move inputs from ./data to the data directory and git-ignore ./data
+  aoc config show
+      print the settings merged from aoc.json files and the flags above
+  aoc config get <setting>
+      print one setting, such as data_dir or cookie_file";
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let mut config = Config::load()?;
+    if let Some(year) = take_flag(&mut args, "--year")? {
+        config.year = year;
+    }
+    if let Some(data_dir) = take_flag(&mut args, "--data-dir")? {
+        config.data_dir = data_dir;
+    }
+    if let Some(cookie_file) = take_flag(&mut args, "--cookie-file")? {
+        config.cookie_file = Some(cookie_file);
+    }
+
+    let config = &config;
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => run(config, rest),
+        Some((command, rest)) if command == "verify" => verify(config, rest),
+        Some((command, [day, rest @ ..])) if command == "animate" => animate(config, day, rest),
+        Some((command, [day, rest @ ..])) if command == "show" => show(config, day, rest),
+        Some((command, [day, rest @ ..])) if command == "image" => export_image(config, day, rest),
+        Some((command, [])) if command == "serve" => serve::serve(config, 8024),
+        Some((command, [flag, port])) if command == "serve" && flag == "--port" => serve::serve(
+            config,
+            port.parse()
+                .map_err(|_| anyhow!("invalid port '{}'", port))?,
+        ),
+        Some((command, [day])) if command == "watch" => {
+            let day = find(config, day)?;
+            watch::watch(&config.data_dir, day.year, day.day)
+        }
+        Some((command, [])) if command == "migrate-inputs" => migrate_inputs(config),
+        Some((command, [show])) if command == "config" && show == "show" => {
+            println!("{}", serde_json::to_string_pretty(config)?);
+            Ok(())
+        }
+        Some((command, [get, setting])) if command == "config" && get == "get" => {
+            println!("{}", config.get(setting)?);
+            Ok(())
+        }
+        _ => Err(anyhow!("{}", USAGE)),
+    }
+}
+
+/// Removes `flag` and its value from anywhere in `args`, so that every command accepts it.
+fn take_flag<T: FromStr>(args: &mut Vec<String>, flag: &str) -> Result<Option<T>> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    let value = parse_value(&mut args[i..].iter().skip(1), flag)?;
+    args.drain(i..i + 2);
+    Ok(Some(value))
+}
+
+fn run(config: &Config, args: &[String]) -> Result<()> {
+    let (selected, mut options, format) = parse_run_args(config, args)?;
+    options.progress &= format != Format::Json;
+    let results = runner::run(&selected, &options);
+    match format {
+        Format::Table => runner::print_table(&results),
+        Format::Json => report::print_json(&results)?,
+    }
+    Ok(())
+}
+
+fn verify(config: &Config, args: &[String]) -> Result<()> {
+    let mut args = args.to_vec();
+    let record = args.iter().any(|arg| arg == "--record");
+    args.retain(|arg| arg != "--record");
+    let (selected, options, _) = parse_run_args(config, &args)?;
+    let results = runner::run(&selected, &options);
+
+    let mut answers = Answers::load(config::repository_root())?;
+    let mut mismatches = 0;
+    for r in &results {
+        let verdict = match &r.outcome {
+            Outcome::Answer(answer) if record => {
+                answers.record(r.year, r.day, r.part, answer);
+                "recorded".to_owned()
+            }
+            Outcome::Answer(answer) => match answers.check(r.year, r.day, r.part, answer) {
+                Verdict::Match => "ok".to_owned(),
+                Verdict::Unknown => "unknown".to_owned(),
+                Verdict::Mismatch { expected } => {
+                    mismatches += 1;
+                    match expected {
+                        Some(expected) => {
+                            format!("MISMATCH: got {}, expected {}", answer, expected)
+                        }
+                        None => format!("MISMATCH: got {}", answer),
+                    }
+                }
+            },
+            _ => {
+                mismatches += 1;
+                "FAILED".to_owned()
+            }
+        };
+        println!(
+            "{} {} part {} {:<20} {}",
+            r.year,
+            day_name(r.day),
+            r.part,
+            r.name,
+            verdict
+        );
+    }
+    if record {
+        answers.save()?;
+        println!(
+            "recorded hashes in {} and answers in {}",
+            ANSWERS_FILE, LOCAL_ANSWERS_FILE
+        );
+    }
+    match mismatches {
+        0 => Ok(()),
+        n => Err(anyhow!("{} of {} parts did not match", n, results.len())),
+    }
+}
+
+/// Reads the day selection and runner options shared by `run` and `verify`.
+fn parse_run_args(
+    config: &Config,
+    args: &[String],
+) -> Result<(Vec<&'static Day>, runner::Options, Format)> {
+    let mut all = false;
+    let mut options = runner::Options {
+        iterations: config.iterations,
+        data_dir: config.data_dir.clone(),
+        ..runner::Options::default()
+    };
+    let mut format = config.format;
+    let mut selected = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--no-cache" => options.cache = false,
+            "--format" => format = value(&mut args, arg)?.parse()?,
+            "--jobs" | "-j" => options.jobs = parse_value(&mut args, arg)?,
+            "--iterations" => options.iterations = parse_value(&mut args, arg)?,
+            "--timeout" => {
+                options.timeout = Duration::try_from_secs_f64(parse_value(&mut args, arg)?)?
+            }
+            day => selected.push(find(config, day)?),
+        }
+    }
+    if all {
+        selected = days::year(config.year)?.iter().collect();
+    } else if selected.is_empty() {
+        return Err(anyhow!("{}", USAGE));
+    }
+    Ok((selected, options, format))
+}
+
+fn animate(config: &Config, day: &str, args: &[String]) -> Result<()> {
+    let day = find(config, day)?;
+    let mut part = 1;
+    let mut every = 1;
+    let mut max_frames = 1000;
+    let mut fps = 10.0;
+    let mut dump = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = parse_value(&mut args, arg)?,
+            "--every" => every = parse_value(&mut args, arg)?,
+            "--max-frames" => max_frames = parse_value(&mut args, arg)?,
+            "--fps" => fps = parse_value::<f64>(&mut args, arg)?.clamp(0.25, 240.0),
+            "--dump" => dump = Some(value(&mut args, arg)?),
+            _ => return Err(anyhow!("{}", USAGE)),
+        }
+    }
+    let part = day
+        .parts
+        .iter()
+        .find(|p| p.part == part)
+        .ok_or_else(|| anyhow!("day {} has no part {}", day.day, part))?;
+
+    let input = read_input(&config.data_dir, day.year, day.day)?;
+    let (answer, frames) = Recorder::new(every, max_frames).record(|| (part.solve)(&input));
+    if frames.is_empty() {
+        return Err(anyhow!(
+            "day {} part {} emits no frames",
+            day.day,
+            part.part
+        ));
+    }
+    match dump {
+        Some(path) => {
+            animation::dump(&frames, BufWriter::new(File::create(path)?))?;
+            println!("wrote {} frames to {}", frames.len(), path);
+        }
+        None => animation::play(&frames, fps)?,
+    }
+    println!("problem{} = {}", part.part, answer?);
+    Ok(())
+}
+
+fn show(config: &Config, day: &str, args: &[String]) -> Result<()> {
+    let day = find(config, day)?;
+    let visualize = day
+        .visualize
+        .ok_or_else(|| anyhow!("{} {} has no visualization", day.year, day_name(day.day)))?;
+    let input = read_input(&config.data_dir, day.year, day.day)?;
+    let output = options::with(day.options, options::parse(args)?, || visualize(&input))?;
+    println!("{}", output);
+    Ok(())
+}
+
+fn export_image(config: &Config, day: &str, args: &[String]) -> Result<()> {
+    let day = find(config, day)?;
+    let mut scale = 4;
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scale" => scale = parse_value(&mut args, arg)?,
+            "-o" | "--output" => output = Some(value(&mut args, arg)?),
+            _ => return Err(anyhow!("{}", USAGE)),
+        }
+    }
+    let output = output.ok_or_else(|| anyhow!("{}", USAGE))?;
+    let extension = Path::new(output).extension().and_then(|e| e.to_str());
+    if extension == Some("svg") {
+        let svg = day
+            .svg
+            .ok_or_else(|| anyhow!("day {} has no SVG drawing", day.day))?;
+        fs::write(
+            output,
+            svg(&read_input(&config.data_dir, day.year, day.day)?)?,
+        )?;
+        println!("wrote {}", output);
+        return Ok(());
+    }
+    let png = match extension {
+        Some("ppm") => false,
+        Some("png") => true,
+        _ => return Err(anyhow!("{} should end in .ppm, .png or .svg", output)),
+    };
+    let grid = day
+        .grid
+        .ok_or_else(|| anyhow!("day {} has no grid to draw", day.day))?;
+
+    let image = Image::from_grid(
+        &grid(&read_input(&config.data_dir, day.year, day.day)?)?,
+        scale,
+        image::default_palette,
+    );
+    let out = BufWriter::new(File::create(output)?);
+    if png {
+        image.write_png(out)?;
+    } else {
+        image.write_ppm(out)?;
+    }
+    println!("wrote {}x{} image to {}", image.width, image.height, output);
+    Ok(())
+}
+
+fn migrate_inputs(config: &Config) -> Result<()> {
+    let legacy = Path::new(LEGACY_DATA_DIR);
+    if config.data_dir == legacy {
+        return Err(anyhow!(
+            "the data directory is already {}",
+            legacy.display()
+        ));
+    }
+    if legacy.exists() {
+        let migration = migrate::migrate_inputs(legacy, &config.data_dir, config.year)?;
+        for (from, to) in &migration.moved {
+            println!("moved {} to {}", from.display(), to.display());
+        }
+        for input in &migration.skipped {
+            println!(
+                "kept {}, which is already in {}",
+                input.display(),
+                config.data_dir.display()
+            );
+        }
+    }
+    if migrate::ignore(Path::new(".gitignore"), "/data/")? {
+        println!("added /data/ to .gitignore");
+    }
+    println!(
+        "commit the removal of {} to stop publishing its inputs",
+        legacy.display()
+    );
+    Ok(())
+}
+
+fn value<'a>(args: &mut impl Iterator<Item = &'a String>, flag: &str) -> Result<&'a str> {
+    args.next()
+        .map(String::as_str)
+        .ok_or_else(|| anyhow!("{} needs a value", flag))
+}
+
+fn parse_value<'a, T: FromStr>(
+    args: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<T> {
+    let value = value(args, flag)?;
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid value '{}' for {}", value, flag))
+}
+
+fn find(config: &Config, day: &str) -> Result<&'static Day> {
+    days::find(config.year, parse_day(day)?)
+}
+
+fn parse_day(day: &str) -> Result<u32> {
+    day.parse()
+        .map_err(|_| anyhow!("invalid day '{}'", day))
+        .and_then(|d| match d {
+            1..=25 => Ok(d),
+            _ => Err(anyhow!("day must be between 1 and 25")),
+        })
+}
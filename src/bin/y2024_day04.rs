@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    advent_of_code::days::main(env!("CARGO_BIN_NAME"))
+}
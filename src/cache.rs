@@ -0,0 +1,128 @@
+use crate::answer::Answer;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where `aoc run` caches answers, relative to the repository root.
+pub const CACHE_FILE: &str = "target/aoc/cache.json";
+
+/// Identifies a cached answer: it is reused only while the day's input and source are unchanged.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Key {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub name: String,
+    pub input_hash: String,
+    pub source_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    key: Key,
+    answer: Answer,
+}
+
+#[derive(Default)]
+pub struct Cache {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Cache {
+    /// Loads the cache at `path`, starting afresh if it is missing or unreadable.
+    pub fn load(path: impl AsRef<Path>) -> Cache {
+        let path = path.as_ref().to_path_buf();
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Cache { path, entries }
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .find(|entry| &entry.key == key)
+            .map(|entry| &entry.answer)
+    }
+
+    /// Stores `answer`, replacing any answer cached for an older input or source of the same part.
+    pub fn insert(&mut self, key: Key, answer: Answer) {
+        self.entries.retain(|entry| {
+            let old = &entry.key;
+            (old.year, old.day, old.part, &old.name) != (key.year, key.day, key.part, &key.name)
+        });
+        self.entries.push(Entry { key, answer });
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is stable between builds.
+pub fn hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf29ce484222325u64, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn hash_lines(lines: &[String]) -> String {
+    hash(lines.join("\n").as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(source: &str) -> Key {
+        Key {
+            year: 2024,
+            day: 1,
+            part: 2,
+            name: "problem2_solution".to_owned(),
+            input_hash: hash(b"3   4"),
+            source_hash: hash(source.as_bytes()),
+        }
+    }
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(hash(b""), "cbf29ce484222325");
+        assert_eq!(hash(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn changed_source_misses() {
+        let mut cache = Cache::default();
+        cache.insert(key("old"), Answer::Unsigned(31));
+        assert_eq!(cache.get(&key("old")), Some(&Answer::Unsigned(31)));
+        assert_eq!(cache.get(&key("new")), None);
+
+        cache.insert(key("new"), Answer::Unsigned(32));
+        assert_eq!(cache.get(&key("old")), None);
+        assert_eq!(cache.get(&key("new")), Some(&Answer::Unsigned(32)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.json", std::process::id()));
+        let mut cache = Cache::load(&path);
+        cache.insert(key("src"), Answer::Unsigned(31));
+        cache.save().unwrap();
+
+        let loaded = Cache::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.get(&key("src")), Some(&Answer::Unsigned(31)));
+    }
+}
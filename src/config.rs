@@ -0,0 +1,188 @@
+use crate::{report::Format, DEFAULT_YEAR};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const CONFIG_FILE: &str = "aoc.json";
+
+/// Where inputs used to be kept, inside the repository.
+pub const LEGACY_DATA_DIR: &str = "./data";
+
+/// Settings shared by every command, merged from the defaults, the user's config, the
+/// repository's config and command-line flags, each overriding the one before.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Config {
+    pub data_dir: PathBuf,
+    /// File holding the adventofcode.com session cookie, used to download inputs.
+    pub cookie_file: Option<PathBuf>,
+    pub year: u32,
+    /// How many times `aoc run` solves each part, reporting the mean time.
+    pub iterations: usize,
+    pub format: Format,
+}
+
+/// One config file, in which every setting is optional.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Layer {
+    data_dir: Option<PathBuf>,
+    cookie_file: Option<PathBuf>,
+    year: Option<u32>,
+    iterations: Option<usize>,
+    format: Option<Format>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            data_dir: default_data_dir(),
+            cookie_file: None,
+            year: DEFAULT_YEAR,
+            iterations: 1,
+            format: Format::Table,
+        }
+    }
+}
+
+impl Config {
+    /// Merges `aoc.json` from the user config directory and then from the repository root,
+    /// skipping either if it does not exist.
+    pub fn load() -> Result<Config> {
+        let mut config = Config::default();
+        let repository = repository_root();
+        for path in xdg_dir("XDG_CONFIG_HOME", ".config")
+            .map(|dir| dir.join("aoc").join(CONFIG_FILE))
+            .into_iter()
+            .chain([repository.join(CONFIG_FILE)])
+        {
+            if path.exists() {
+                config.merge(read_layer(&path)?);
+            }
+        }
+        Ok(config)
+    }
+
+    fn merge(&mut self, layer: Layer) {
+        if let Some(data_dir) = layer.data_dir {
+            self.data_dir = data_dir;
+        }
+        if layer.cookie_file.is_some() {
+            self.cookie_file = layer.cookie_file;
+        }
+        self.year = layer.year.unwrap_or(self.year);
+        self.iterations = layer.iterations.unwrap_or(self.iterations);
+        self.format = layer.format.unwrap_or(self.format);
+    }
+
+    /// The value of one setting as plain text, for scripts.
+    pub fn get(&self, key: &str) -> Result<String> {
+        match serde_json::to_value(self)?.get(key) {
+            Some(serde_json::Value::String(s)) => Ok(s.clone()),
+            Some(serde_json::Value::Null) => Ok(String::new()),
+            Some(value) => Ok(value.to_string()),
+            None => Err(anyhow!("unknown setting '{}'", key)),
+        }
+    }
+}
+
+/// Relative paths in a config file are relative to the directory it is in.
+fn read_layer(path: &Path) -> Result<Layer> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut layer: Layer = serde_json::from_str(&json)
+        .with_context(|| format!("invalid config in {}", path.display()))?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    layer.data_dir = layer.data_dir.map(|p| dir.join(p));
+    layer.cookie_file = layer.cookie_file.map(|p| dir.join(p));
+    Ok(layer)
+}
+
+/// The repository the current directory is in, or else the one this was built from. Its
+/// `aoc.json`, `answers.json` and answer cache are found there wherever commands are run from.
+pub fn repository_root() -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|dir| repository_dir(&dir))
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
+
+/// The nearest directory from `start` up that has a `Cargo.toml`, so that commands run from
+/// anywhere in the repository find its config.
+fn repository_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Inputs are kept out of the repository, in `$XDG_CACHE_HOME/aoc/inputs` (by default
+/// `~/.cache/aoc/inputs`), since Advent of Code asks that they not be published.
+pub fn default_data_dir() -> PathBuf {
+    xdg_dir("XDG_CACHE_HOME", ".cache")
+        .map(|dir| dir.join("aoc").join("inputs"))
+        .unwrap_or_else(|| PathBuf::from(LEGACY_DATA_DIR))
+}
+
+/// `$var`, falling back to `fallback` in the home directory.
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(fallback)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_layers_override_earlier_ones() {
+        let mut config = Config::default();
+        config.merge(serde_json::from_str(r#"{"year": 2023, "format": "json"}"#).unwrap());
+        config.merge(serde_json::from_str(r#"{"year": 2022, "data_dir": "inputs"}"#).unwrap());
+        assert_eq!(
+            config,
+            Config {
+                data_dir: PathBuf::from("inputs"),
+                year: 2022,
+                format: Format::Json,
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn finds_repository_config_from_subdirectories() {
+        let dir = env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        let nested = dir.join("src").join("days");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+        fs::write(dir.join(CONFIG_FILE), r#"{"data_dir": "inputs"}"#).unwrap();
+        let found = repository_dir(&nested);
+        let layer = read_layer(&dir.join(CONFIG_FILE)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(found, Some(dir.clone()));
+        assert_eq!(layer.data_dir, Some(dir.join("inputs")));
+    }
+
+    #[test]
+    fn rejects_unknown_settings() {
+        assert!(serde_json::from_str::<Layer>(r#"{"yaer": 2023}"#).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let config = Config::default();
+        assert_eq!(config.get("year").unwrap(), "2024");
+        assert_eq!(
+            config.get("data_dir").unwrap(),
+            default_data_dir().to_str().unwrap()
+        );
+        assert_eq!(config.get("cookie_file").unwrap(), "");
+        assert_eq!(config.get("format").unwrap(), "table");
+        assert!(config.get("cookie").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::days::Part;
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
+
+fn problem1_solution(input: &[String]) -> Result<usize> {
+    Ok(input.iter().dedup().count())
+}
+
+fn problem2_solution(input: &[String]) -> Result<usize> {
+    input
+        .iter()
+        .dedup_with_count()
+        .map(|tuple| tuple.0)
+        .max()
+        .ok_or(anyhow!("max of empty input"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "A
+A
+B
+B
+B";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 3);
+    }
+}
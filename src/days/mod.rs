@@ -0,0 +1,158 @@
+use crate::{
+    answer::Answer, config::Config, day_name, input_path, options, progress::Progress, read_input,
+};
+use anyhow::{anyhow, Context, Result};
+use std::fs::File;
+
+pub mod day_template;
+pub mod y2024;
+
+pub struct Part {
+    pub part: u32,
+    pub name: &'static str,
+    pub solve: fn(&[String]) -> Result<Answer>,
+}
+
+impl Part {
+    pub const fn new(
+        part: u32,
+        name: &'static str,
+        solve: fn(&[String]) -> Result<Answer>,
+    ) -> Self {
+        Part { part, name, solve }
+    }
+}
+
+pub type Visualize = fn(&[String]) -> Result<String>;
+pub type Grid = fn(&[String]) -> Result<Vec<String>>;
+/// Solves every part straight from the input file, for inputs too large to load.
+pub type Stream = fn(File) -> Result<Vec<Answer>>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    /// The day's source, whose hash decides whether cached answers are still valid.
+    pub source: &'static str,
+    pub parts: &'static [Part],
+    /// Draws a picture of the puzzle state as text, for days that have one.
+    pub visualize: Option<Visualize>,
+    /// Draws the puzzle state as rows of cells, one character each, for image export.
+    pub grid: Option<Grid>,
+    /// Draws the puzzle geometry as an SVG document.
+    pub svg: Option<Visualize>,
+    pub stream: Option<Stream>,
+    /// The names of the options its parts and visualizations read with `options::get`.
+    pub options: &'static [&'static str],
+}
+
+impl Day {
+    pub const fn new(year: u32, day: u32, source: &'static str, parts: &'static [Part]) -> Self {
+        Day {
+            year,
+            day,
+            source,
+            parts,
+            visualize: None,
+            grid: None,
+            svg: None,
+            stream: None,
+            options: &[],
+        }
+    }
+
+    pub const fn with_visualization(mut self, visualize: Visualize) -> Self {
+        self.visualize = Some(visualize);
+        self
+    }
+
+    pub const fn with_grid(mut self, grid: Grid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    pub const fn with_svg(mut self, svg: Visualize) -> Self {
+        self.svg = Some(svg);
+        self
+    }
+
+    pub const fn with_stream(mut self, stream: Stream) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    pub const fn with_options(mut self, options: &'static [&'static str]) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// `problemN`, followed by the implementation's name when the part has several.
+    pub fn label(&self, part: &Part) -> String {
+        if self.parts.iter().filter(|p| p.part == part.part).count() > 1 {
+            format!("problem{} ({})", part.part, part.name)
+        } else {
+            format!("problem{}", part.part)
+        }
+    }
+}
+
+/// Every year's registered days, in order.
+pub const YEARS: [&[Day]; 1] = [&y2024::DAYS];
+
+/// The registered days of `year`.
+pub fn year(year: u32) -> Result<&'static [Day]> {
+    YEARS
+        .into_iter()
+        .find(|days| days.first().is_some_and(|d| d.year == year))
+        .ok_or_else(|| anyhow!("no days are registered for {}", year))
+}
+
+pub fn find(year: u32, day: u32) -> Result<&'static Day> {
+    self::year(year)?
+        .iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| anyhow!("{} {} is not registered", year, day_name(day)))
+}
+
+/// Entry point shared by the `yYYYY_dayNN` binaries, which pass their own `CARGO_BIN_NAME`.
+/// With `--stream`, days that can solve straight from the input file do so instead of loading it.
+pub fn main(bin_name: &str) -> Result<()> {
+    let (year, day) = bin_name
+        .strip_prefix('y')
+        .and_then(|name| name.split_once("_day"))
+        .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)))
+        .ok_or_else(|| anyhow!("{} is not a day binary", bin_name))?;
+    let day = find(year, day)?;
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let stream = args.iter().position(|arg| arg == "--stream").map(|i| {
+        args.remove(i);
+    });
+    let options = options::parse(&args)?;
+    let data_dir = Config::load()?.data_dir;
+    if stream.is_some() {
+        let stream = day
+            .stream
+            .ok_or_else(|| anyhow!("{} {} can't stream its input", year, day_name(day.day)))?;
+        let path = input_path(&data_dir, day.year, day.day);
+        let file =
+            File::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+        return options::with(day.options, options, || {
+            for (i, answer) in stream(file)?.into_iter().enumerate() {
+                println!("problem{} = {}", i + 1, answer);
+            }
+            Ok(())
+        });
+    }
+    let lines = read_input(&data_dir, day.year, day.day)?;
+    let progress = Progress::for_stderr();
+    options::with(day.options, options, || {
+        for part in day.parts {
+            let label = day.label(part);
+            let answer = match &progress {
+                Some(progress) => progress.track(label.clone(), || (part.solve)(&lines))?,
+                None => (part.solve)(&lines)?,
+            };
+            println!("{} = {}", label, answer);
+        }
+        Ok(())
+    })
+}
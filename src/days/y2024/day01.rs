@@ -0,0 +1,898 @@
+use anyhow::{anyhow, Result};
+use itertools::{process_results, Itertools};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    fmt::{self, Display, Formatter},
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    iter,
+    path::PathBuf,
+    process,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use crate::{answer::Answer, days::Part, options};
+
+/// Read by the report in `visualize`.
+pub const OPTIONS: [&str; 4] = ["metric", "cap", "top", "optimal"];
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
+
+/// 8 MiB of values per column per run when streaming an input file.
+const STREAM_CHUNK_LEN: usize = 1 << 20;
+
+/// Both answers from the input file, sorted externally so that it never has to fit in memory.
+pub fn stream(input: File) -> Result<Vec<Answer>> {
+    let (distance, similarity) = external_solutions(input, STREAM_CHUNK_LEN)?;
+    Ok(vec![distance.into(), similarity.into()])
+}
+
+fn problem1_solution(input: &[String]) -> Result<usize> {
+    solution(input, 1)
+}
+
+fn problem2_solution(input: &[String]) -> Result<usize> {
+    solution(input, 2)
+}
+
+/// The answer the last part to solve left for the other part, with the input it was for.
+static LEFT_OVER: Mutex<Option<(Vec<String>, u32, usize)>> = Mutex::new(None);
+
+/// The answer to `part`. The first part to run on an input solves both and leaves the other's
+/// answer, so running both parts, as the day binary, `aoc run` and `aoc verify` do, sorts the
+/// columns once.
+fn solution(input: &[String], part: u32) -> Result<usize> {
+    let mut left_over = LEFT_OVER.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some((_, _, answer)) = left_over.take_if(|(lines, p, _)| *p == part && lines == input) {
+        return Ok(answer);
+    }
+    let (distance, similarity) = solutions(input)?;
+    let (answer, other) = match part {
+        1 => (distance, similarity),
+        _ => (similarity, distance),
+    };
+    *left_over = Some((input.to_vec(), 3 - part, other));
+    Ok(answer)
+}
+
+/// Both answers from one sort of each column.
+pub fn solutions(input: &[String]) -> Result<(usize, usize)> {
+    let (lhs, rhs) = sorted_columns(input)?;
+    Ok((distance(&lhs, &rhs), similarity_sorted(&lhs, &rhs)))
+}
+
+fn sorted_columns(input: &[String]) -> Result<(Vec<usize>, Vec<usize>)> {
+    let (mut lhs, mut rhs) = parse_input(input)?;
+    lhs.sort_unstable();
+    rhs.sort_unstable();
+    Ok((lhs, rhs))
+}
+
+/// The total distance between two sorted columns.
+pub fn distance(lhs: &[usize], rhs: &[usize]) -> usize {
+    lhs.iter().zip(rhs).map(|(l, r)| l.abs_diff(*r)).sum()
+}
+
+/// The similarity score of two columns in any order, counting the right column in a `HashMap`.
+/// Slower than sorting, and kept to compare against in tests and the bench.
+pub fn similarity_counts(lhs: &[usize], rhs: &[usize]) -> usize {
+    let counts = rhs.iter().counts();
+    lhs.iter().map(|l| l * counts.get(l).unwrap_or(&0)).sum()
+}
+
+/// The similarity score of two sorted columns, without hashing or allocating.
+pub fn similarity_sorted(lhs: &[usize], rhs: &[usize]) -> usize {
+    sorted_similarity(lhs.iter().copied(), rhs.iter().copied())
+}
+
+fn parse_input(input: &[String]) -> Result<(Vec<usize>, Vec<usize>)> {
+    if input.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let [lhs, rhs] = <[_; 2]>::try_from(parse_columns(input)?)
+        .map_err(|columns| anyhow!("expected 2 columns, found {}", columns.len()))?;
+    Ok((lhs, rhs))
+}
+
+/// Parses any number of whitespace-separated columns, which every line must have.
+pub fn parse_columns<T>(input: &[String]) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr + Clone,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let mut columns: Vec<Vec<T>> = Vec::new();
+    for (i, line) in input.iter().enumerate() {
+        let values = line
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<T>, _>>()?;
+        if i == 0 {
+            columns = vec![Vec::with_capacity(input.len()); values.len()];
+        }
+        if values.len() != columns.len() {
+            return Err(anyhow!(
+                "line {} has {} columns, expected {}",
+                i + 1,
+                values.len(),
+                columns.len()
+            ));
+        }
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    Ok(columns)
+}
+
+/// The sorted-pairing distance and the similarity score of every pair of columns, where
+/// `similarity[i][j]` scores column `i` against the counts in column `j`.
+pub struct Matrices {
+    pub distance: Vec<Vec<usize>>,
+    pub similarity: Vec<Vec<usize>>,
+}
+
+pub fn pairwise(mut columns: Vec<Vec<usize>>) -> Matrices {
+    columns.iter_mut().for_each(|column| column.sort_unstable());
+    let matrix = |f: fn(&[usize], &[usize]) -> usize| {
+        columns
+            .iter()
+            .map(|a| columns.iter().map(|b| f(a, b)).collect())
+            .collect()
+    };
+    Matrices {
+        distance: matrix(distance),
+        similarity: matrix(similarity_sorted),
+    }
+}
+
+/// Prints the distance and similarity matrices of however many columns the input has, and for
+/// two columns a report of the pairs with the biggest distances marked.
+pub fn visualize(input: &[String]) -> Result<String> {
+    let metric = metric_from_options()?;
+    let top = options::get("top")?.unwrap_or(5);
+    let optimal = options::get("optimal")?.unwrap_or(false);
+    let columns = parse_columns::<i64>(input)?;
+    let mut sections = Vec::new();
+    // Similarity only makes sense for the puzzle's unsigned lists.
+    if let Ok(columns) = parse_columns::<usize>(input) {
+        let matrices = pairwise(columns);
+        sections.push(format!("distance\n{}", format_matrix(&matrices.distance)));
+        sections.push(format!(
+            "similarity\n{}",
+            format_matrix(&matrices.similarity)
+        ));
+    }
+    if let Ok([lhs, rhs]) = <[_; 2]>::try_from(columns) {
+        if optimal {
+            let report = optimal_report(&lhs, &rhs, &*metric, top)?;
+            sections.push(format!("optimal pairs\n{}", report));
+        } else {
+            let report = pairing_report(lhs, rhs, &*metric, top);
+            sections.push(format!("pairs\n{}", report));
+        }
+    }
+    Ok(sections.join("\n"))
+}
+
+/// The metric named by `--metric abs|squared|capped`, with `--cap N` for `capped`.
+fn metric_from_options() -> Result<Box<dyn Metric>> {
+    let cap = options::get::<u64>("cap")?;
+    let metric = options::get::<String>("metric")?;
+    match (metric.as_deref().unwrap_or("abs"), cap) {
+        ("capped", Some(cap)) => Ok(Box::new(Capped(cap))),
+        ("capped", None) => Err(anyhow!("--metric capped needs --cap N")),
+        (_, Some(_)) => Err(anyhow!("--cap only applies to --metric capped")),
+        ("abs", None) => Ok(Box::new(Absolute)),
+        ("squared", None) => Ok(Box::new(Squared)),
+        (other, None) => Err(anyhow!("unknown metric '{}'", other)),
+    }
+}
+
+fn format_matrix(matrix: &[Vec<usize>]) -> String {
+    let width = matrix
+        .iter()
+        .flatten()
+        .map(|n| n.to_string().len())
+        .chain([matrix.len().to_string().len() + 1])
+        .max()
+        .unwrap_or(0);
+    let header = (1..=matrix.len())
+        .map(|j| format!("{:>width$}", format!("c{}", j)))
+        .join(" ");
+    let rows = matrix.iter().enumerate().map(|(i, row)| {
+        let cells = row.iter().map(|n| format!("{:>width$}", n)).join(" ");
+        format!("{:>width$} {}", format!("c{}", i + 1), cells)
+    });
+    iter::once(format!("{:>width$} {}", "", header))
+        .chain(rows)
+        .join("\n")
+}
+
+/// The cost of pairing two values, which `distance_by` sums over the sorted pairs. Closures
+/// taking two `i64`s are metrics too.
+pub trait Metric {
+    fn cost(&self, l: i64, r: i64) -> u64;
+}
+
+/// The puzzle's metric.
+pub struct Absolute;
+
+pub struct Squared;
+
+/// The absolute difference, but no more than the cap, so a few outliers can't dominate.
+pub struct Capped(pub u64);
+
+impl Metric for Absolute {
+    fn cost(&self, l: i64, r: i64) -> u64 {
+        l.abs_diff(r)
+    }
+}
+
+impl Metric for Squared {
+    fn cost(&self, l: i64, r: i64) -> u64 {
+        l.abs_diff(r).saturating_pow(2)
+    }
+}
+
+impl Metric for Capped {
+    fn cost(&self, l: i64, r: i64) -> u64 {
+        l.abs_diff(r).min(self.0)
+    }
+}
+
+impl<F: Fn(i64, i64) -> u64> Metric for F {
+    fn cost(&self, l: i64, r: i64) -> u64 {
+        self(l, r)
+    }
+}
+
+/// The total cost of two sorted columns under `metric`.
+pub fn distance_by(lhs: &[i64], rhs: &[i64], metric: &(impl Metric + ?Sized)) -> u64 {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(l, r)| metric.cost(*l, *r))
+        .fold(0, u64::saturating_add)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Pairing {
+    pub lhs: i64,
+    pub rhs: i64,
+    pub cost: u64,
+}
+
+/// Every sorted pair with its cost, and which of them contribute most to the total.
+#[derive(Debug)]
+pub struct Report {
+    pub pairs: Vec<Pairing>,
+    pub total: u64,
+    /// Indices into `pairs`, most costly first, earlier pairs winning ties.
+    pub top: Vec<usize>,
+}
+
+pub fn pairing_report(
+    mut lhs: Vec<i64>,
+    mut rhs: Vec<i64>,
+    metric: &(impl Metric + ?Sized),
+    top: usize,
+) -> Report {
+    lhs.sort_unstable();
+    rhs.sort_unstable();
+    let pairs = lhs
+        .into_iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| Pairing {
+            lhs,
+            rhs,
+            cost: metric.cost(lhs, rhs),
+        })
+        .collect_vec();
+    Report::new(pairs, top)
+}
+
+/// Like `pairing_report`, but for the cheapest pairing under `metric`, listed by left value.
+pub fn optimal_report(
+    lhs: &[i64],
+    rhs: &[i64],
+    metric: &(impl Metric + ?Sized),
+    top: usize,
+) -> Result<Report> {
+    let assignment = optimal_pairing(lhs, rhs, metric)?;
+    let mut pairs = assignment
+        .rhs_for
+        .iter()
+        .enumerate()
+        .map(|(i, &j)| Pairing {
+            lhs: lhs[i],
+            rhs: rhs[j],
+            cost: metric.cost(lhs[i], rhs[j]),
+        })
+        .collect_vec();
+    pairs.sort_by_key(|p| (p.lhs, p.rhs));
+    Ok(Report::new(pairs, top))
+}
+
+impl Report {
+    fn new(pairs: Vec<Pairing>, top: usize) -> Self {
+        let mut order = (0..pairs.len()).collect_vec();
+        order.sort_by_key(|&i| Reverse(pairs[i].cost));
+        order.truncate(top);
+        Report {
+            total: pairs.iter().map(|p| p.cost).fold(0, u64::saturating_add),
+            pairs,
+            top: order,
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let width = self
+            .pairs
+            .iter()
+            .flat_map(|p| [p.lhs.to_string().len(), p.rhs.to_string().len()])
+            .chain([self.total.to_string().len(), "right".len()])
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:>5}  {:>width$}  {:>width$}  {:>width$}",
+            "#", "left", "right", "cost"
+        )?;
+        for (i, pair) in self.pairs.iter().enumerate() {
+            let rank = self.top.iter().position(|&t| t == i);
+            writeln!(
+                f,
+                "{:>5}  {:>width$}  {:>width$}  {:>width$}{}",
+                i + 1,
+                pair.lhs,
+                pair.rhs,
+                pair.cost,
+                rank.map(|r| format!("  <- top {}", r + 1))
+                    .unwrap_or_default()
+            )?;
+        }
+        write!(
+            f,
+            "{:>5}  {:>width$}  {:>width$}  {:>width$}",
+            "", "", "total", self.total
+        )
+    }
+}
+
+/// A pairing of the two columns: `rhs_for[i]` is the index of the right value paired with the
+/// `i`th left value.
+#[derive(Debug)]
+pub struct Assignment {
+    pub rhs_for: Vec<usize>,
+    pub cost: u64,
+}
+
+/// The cheapest pairing of two columns in any order under any metric, found with the Hungarian
+/// algorithm in O(n³). Sorting both columns is only optimal for metrics like `Absolute`.
+pub fn optimal_pairing(
+    lhs: &[i64],
+    rhs: &[i64],
+    metric: &(impl Metric + ?Sized),
+) -> Result<Assignment> {
+    if lhs.len() != rhs.len() {
+        return Err(anyhow!(
+            "columns have different lengths, {} and {}",
+            lhs.len(),
+            rhs.len()
+        ));
+    }
+    let n = lhs.len();
+    let cost = |i: usize, j: usize| i128::from(metric.cost(lhs[i - 1], rhs[j - 1]));
+    // Row and column potentials, and the row matched to each column, all indexed from 1 so that
+    // column 0 can hold the row being added.
+    let (mut u, mut v) = (vec![0i128; n + 1], vec![0i128; n + 1]);
+    let mut row_for = vec![0; n + 1];
+    let mut way = vec![0; n + 1];
+    for i in 1..=n {
+        row_for[0] = i;
+        let mut j0 = 0;
+        let mut min = vec![i128::MAX; n + 1];
+        let mut used = vec![false; n + 1];
+        // Grow an alternating tree from row i until it reaches a free column.
+        while row_for[j0] != 0 {
+            used[j0] = true;
+            let i0 = row_for[j0];
+            let (mut delta, mut j1) = (i128::MAX, 0);
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let reduced = cost(i0, j) - u[i0] - v[j];
+                if reduced < min[j] {
+                    min[j] = reduced;
+                    way[j] = j0;
+                }
+                if min[j] < delta {
+                    delta = min[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[row_for[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min[j] -= delta;
+                }
+            }
+            j0 = j1;
+        }
+        // Flip the matching along the augmenting path.
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_for[j0] = row_for[j1];
+            j0 = j1;
+        }
+    }
+    let mut rhs_for = vec![0; n];
+    for j in 1..=n {
+        rhs_for[row_for[j] - 1] = j - 1;
+    }
+    let cost = rhs_for
+        .iter()
+        .enumerate()
+        .map(|(i, &j)| metric.cost(lhs[i], rhs[j]))
+        .fold(0, u64::saturating_add);
+    Ok(Assignment { rhs_for, cost })
+}
+
+fn parse_line(line: &str) -> Result<(usize, usize)> {
+    let (l, r) = line
+        .split_whitespace()
+        .collect_tuple()
+        .ok_or_else(|| anyhow!("Unexpected input"))?;
+    Ok((l.parse()?, r.parse()?))
+}
+
+/// Solves both parts for lists too large to hold in memory. Each column is sorted in chunks of
+/// `chunk_len` values that are spilled to temporary files, and the sorted chunks are then merged,
+/// at most `MAX_FAN_IN` at a time, so only one chunk and one value per merged run are held at a
+/// time.
+pub fn external_solutions(input: impl Read, chunk_len: usize) -> Result<(usize, usize)> {
+    let chunk_len = chunk_len.max(1);
+    let (mut lhs_runs, mut rhs_runs) = (Vec::new(), Vec::new());
+    let mut lhs = Vec::with_capacity(chunk_len);
+    let mut rhs = Vec::with_capacity(chunk_len);
+    for line in BufReader::new(input).lines() {
+        let (l, r) = parse_line(&line?)?;
+        lhs.push(l);
+        rhs.push(r);
+        if lhs.len() == chunk_len {
+            lhs_runs.push(SortedRun::spill(&mut lhs)?);
+            rhs_runs.push(SortedRun::spill(&mut rhs)?);
+        }
+    }
+    if !lhs.is_empty() {
+        lhs_runs.push(SortedRun::spill(&mut lhs)?);
+        rhs_runs.push(SortedRun::spill(&mut rhs)?);
+    }
+
+    let (lhs_runs, rhs_runs) = (reduce(lhs_runs)?, reduce(rhs_runs)?);
+    let (lhs, rhs) = (merge(&lhs_runs)?, merge(&rhs_runs)?);
+    Ok(process_results(lhs, |lhs| {
+        process_results(rhs, |rhs| sorted_solutions(lhs, rhs))
+    })??)
+}
+
+/// The total distance and similarity score of two sorted columns of the same length, from one
+/// walk over both in order of value. With `l` and `r` values up to `x` in each column, `|l - r|`
+/// of the sorted pairs span the gap from `x` to the next value, each adding its width.
+fn sorted_solutions(
+    lhs: impl Iterator<Item = usize>,
+    rhs: impl Iterator<Item = usize>,
+) -> (usize, usize) {
+    let (mut lhs, mut rhs) = (lhs.peekable(), rhs.peekable());
+    let (mut l, mut r) = (0usize, 0usize);
+    let (mut distance, mut similarity) = (0, 0);
+    let mut last = None;
+    while let Some(x) = match (lhs.peek(), rhs.peek()) {
+        (Some(&a), Some(&b)) => Some(a.min(b)),
+        (a, b) => a.or(b).copied(),
+    } {
+        if let Some(last) = last {
+            distance += l.abs_diff(r) * (x - last);
+        }
+        let (mut a, mut b) = (0, 0);
+        while lhs.next_if_eq(&x).is_some() {
+            a += 1;
+        }
+        while rhs.next_if_eq(&x).is_some() {
+            b += 1;
+        }
+        similarity += x * a * b;
+        (l, r, last) = (l + a, r + b, Some(x));
+    }
+    (distance, similarity)
+}
+
+/// The similarity score of two sorted columns, found by walking both in step. Runs of equal
+/// left values reuse the count of the first.
+fn sorted_similarity(lhs: impl Iterator<Item = usize>, rhs: impl Iterator<Item = usize>) -> usize {
+    let mut rhs = rhs.peekable();
+    let mut last = None;
+    let mut total = 0;
+    for l in lhs {
+        let count = match last {
+            Some((value, count)) if value == l => count,
+            _ => {
+                while rhs.next_if(|&r| r < l).is_some() {}
+                let mut count = 0;
+                while rhs.next_if_eq(&l).is_some() {
+                    count += 1;
+                }
+                last = Some((l, count));
+                count
+            }
+        };
+        total += l * count;
+    }
+    total
+}
+
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+/// The most runs merged at once, which bounds the files open at a time whatever the input's size.
+const MAX_FAN_IN: usize = 64;
+
+/// A sorted chunk of a column, stored in a temporary file that is removed on drop.
+struct SortedRun {
+    path: PathBuf,
+}
+
+impl SortedRun {
+    /// Sorts `values` and moves them into a new run, leaving `values` empty.
+    fn spill(values: &mut Vec<usize>) -> Result<SortedRun> {
+        values.sort_unstable();
+        SortedRun::write(values.drain(..).map(Ok))
+    }
+
+    /// Writes already sorted `values` to a new run. The run's file must not exist yet, so that a
+    /// file or symlink planted at its path in a shared temporary directory is never written
+    /// through; such paths are skipped.
+    fn write(values: impl Iterator<Item = io::Result<usize>>) -> Result<SortedRun> {
+        let (run, file) = loop {
+            let path = env::temp_dir().join(format!(
+                "aoc-day01-{}-{}.bin",
+                process::id(),
+                NEXT_RUN.fetch_add(1, Ordering::Relaxed)
+            ));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (SortedRun { path }, file),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+        let mut out = BufWriter::new(file);
+        for value in values {
+            out.write_all(&(value? as u64).to_le_bytes())?;
+        }
+        out.flush()?;
+        Ok(run)
+    }
+
+    fn read(&self) -> Result<impl Iterator<Item = io::Result<usize>>> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        Ok(iter::from_fn(move || {
+            let mut bytes = [0; 8];
+            match reader.read_exact(&mut bytes) {
+                Ok(()) => Some(Ok(u64::from_le_bytes(bytes) as usize)),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => None,
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
+}
+
+impl Drop for SortedRun {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Merges `runs` in passes of `MAX_FAN_IN` at a time until no more than that are left.
+fn reduce(mut runs: Vec<SortedRun>) -> Result<Vec<SortedRun>> {
+    while runs.len() > MAX_FAN_IN {
+        runs = runs
+            .chunks(MAX_FAN_IN)
+            .map(|runs| SortedRun::write(merge(runs)?))
+            .collect::<Result<_>>()?;
+    }
+    Ok(runs)
+}
+
+/// Merges sorted runs into one sorted stream.
+fn merge(runs: &[SortedRun]) -> Result<impl Iterator<Item = io::Result<usize>>> {
+    let mut readers = runs
+        .iter()
+        .map(SortedRun::read)
+        .collect::<Result<Vec<_>>>()?;
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    for (i, reader) in readers.iter_mut().enumerate() {
+        if let Some(value) = reader.next() {
+            heap.push(Reverse((value?, i)));
+        }
+    }
+    Ok(iter::from_fn(move || {
+        let Reverse((value, i)) = heap.pop()?;
+        match readers[i].next() {
+            Some(Ok(next)) => heap.push(Reverse((next, i))),
+            Some(Err(e)) => return Some(Err(e)),
+            None => {}
+        }
+        Some(Ok(value))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Lcg;
+
+    const INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 11);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 31);
+    }
+
+    /// `len` lines of values from a small range, so that both columns have many repeats.
+    fn generate(len: usize, seed: u64) -> Vec<String> {
+        let mut lcg = Lcg::new(seed);
+        let mut next = move || 10 + lcg.below(90);
+        (0..len)
+            .map(|_| format!("{}   {}", next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn external_matches_in_memory() {
+        for (len, chunk_len) in [
+            (0, 4),
+            (1, 4),
+            (100, 7),
+            (1000, 1000),
+            (2000, 33),
+            (5000, 1),
+        ] {
+            let input = generate(len, len as u64);
+            let expected = (
+                problem1_solution(&input).unwrap(),
+                problem2_solution(&input).unwrap(),
+            );
+            let external = external_solutions(input.join("\n").as_bytes(), chunk_len).unwrap();
+            assert_eq!(
+                external, expected,
+                "{} lines in chunks of {}",
+                len, chunk_len
+            );
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn runs_skip_planted_paths() {
+        let target = env::temp_dir().join(format!("aoc-day01-target-{}", process::id()));
+        fs::write(&target, "keep").unwrap();
+        let next = NEXT_RUN.load(Ordering::Relaxed);
+        let planted = (next..next + 64)
+            .map(|n| env::temp_dir().join(format!("aoc-day01-{}-{}.bin", process::id(), n)))
+            .collect_vec();
+        for path in &planted {
+            std::os::unix::fs::symlink(&target, path).unwrap();
+        }
+        let run = SortedRun::spill(&mut vec![3, 1, 2]).unwrap();
+        let values = run.read().unwrap().collect::<io::Result<Vec<_>>>().unwrap();
+        assert_eq!(values, [1, 2, 3]);
+        assert_eq!(fs::read_to_string(&target).unwrap(), "keep");
+        for path in planted.iter().chain([&target]) {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn external_example() {
+        let answers = external_solutions(INPUT.as_bytes(), 2).unwrap();
+        assert_eq!(answers, (11, 31));
+    }
+
+    #[test]
+    fn streams_a_file() {
+        let path = env::temp_dir().join(format!("aoc-day01-input-{}.txt", process::id()));
+        fs::write(&path, INPUT).unwrap();
+        let answers = stream(File::open(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            answers.unwrap(),
+            [Answer::from(11usize), Answer::from(31usize)]
+        );
+    }
+
+    #[test]
+    fn problem2_real_input() {
+        let Some(input) = crate::test_input(2024, 1) else {
+            return;
+        };
+        let (lhs, rhs) = parse_input(&input).unwrap();
+        let naive = lhs
+            .iter()
+            .map(|l| l * rhs.iter().filter(|&r| r == l).count())
+            .sum::<usize>();
+        assert_eq!(problem2_solution(&input).unwrap(), naive);
+        assert_eq!(similarity_counts(&lhs, &rhs), naive);
+    }
+
+    #[test]
+    fn pairwise_columns() {
+        let input = ["3 4 3", "4 3 4", "2 5 2", "1 3 1", "3 9 3", "3 3 3"].map(String::from);
+        let matrices = pairwise(parse_columns(&input).unwrap());
+        assert_eq!(matrices.distance[0][1], 11);
+        assert_eq!(matrices.similarity[0][1], 31);
+        assert_eq!(matrices.distance[0][2], 0);
+        assert_eq!(matrices.distance[1][0], 11);
+        assert_eq!(
+            visualize(&input).unwrap(),
+            "distance
+   c1 c2 c3
+c1  0 11  0
+c2 11  0 11
+c3  0 11  0
+similarity
+   c1 c2 c3
+c1 34 31 34
+c2 31 45 31
+c3 34 31 34"
+        );
+    }
+
+    #[test]
+    fn metrics() {
+        let (lhs, rhs) = (vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]);
+        let sorted = |mut v: Vec<i64>| {
+            v.sort_unstable();
+            v
+        };
+        let (lhs, rhs) = (sorted(lhs), sorted(rhs));
+        assert_eq!(distance_by(&lhs, &rhs, &Absolute), 11);
+        assert_eq!(distance_by(&lhs, &rhs, &Squared), 35);
+        assert_eq!(distance_by(&lhs, &rhs, &Capped(2)), 8);
+        let over_three = |l: i64, r: i64| u64::from(l.abs_diff(r) > 3);
+        assert_eq!(distance_by(&lhs, &rhs, &over_three), 1);
+        assert_eq!(distance_by(&[-3, -1], &[-2, 2], &Absolute), 1 + 3);
+        let huge = [i64::MIN, i64::MIN];
+        assert_eq!(distance_by(&huge, &[0, 0], &Squared), u64::MAX);
+    }
+
+    #[test]
+    fn report_options() {
+        let input = ["3   4", "4   3", "-2   5", "1   3", "3   9", "3   3"].map(String::from);
+        let show = |options: &[(&str, &str)]| {
+            let options = options
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            options::with(&OPTIONS, options, || visualize(&input))
+        };
+        let report = show(&[]).unwrap();
+        assert!(report.starts_with("pairs\n"));
+        assert!(report.ends_with("total     15"));
+        let squared = show(&[("metric", "squared"), ("top", "1")]).unwrap();
+        assert!(squared.contains("    1     -2      3     25  <- top 1\n"));
+        assert!(squared.ends_with("total     59"));
+        let capped = show(&[("metric", "capped"), ("cap", "2")]).unwrap();
+        assert!(capped.ends_with("total      9"));
+        // Sorting is already optimal for convex costs like these.
+        let optimal = show(&[("metric", "squared"), ("optimal", "true")]).unwrap();
+        assert!(optimal.contains("optimal pairs\n"));
+        assert!(optimal.ends_with("total     59"));
+        assert!(show(&[("metric", "capped")]).is_err());
+        assert!(show(&[("cap", "2")]).is_err());
+        assert!(show(&[("metric", "cubed")]).is_err());
+        assert!(show(&[("metrc", "abs")]).is_err());
+    }
+
+    #[test]
+    fn report_marks_top_pairs() {
+        let input = ["3   4", "4   3", "-2   5", "1   3", "3   9", "3   3"].map(String::from);
+        let [lhs, rhs] = <[_; 2]>::try_from(parse_columns::<i64>(&input).unwrap()).unwrap();
+        let report = pairing_report(lhs, rhs, &Absolute, 2);
+        assert_eq!(report.total, 15);
+        assert_eq!(report.top, [0, 5]);
+        assert_eq!(
+            report.to_string(),
+            "    #   left  right   cost
+    1     -2      3      5  <- top 1
+    2      1      3      2
+    3      3      3      0
+    4      3      4      1
+    5      3      5      2
+    6      4      9      5  <- top 2
+              total     15"
+        );
+    }
+
+    #[test]
+    fn optimal_matches_sorted_for_abs_diff() {
+        for seed in 0..20 {
+            let columns = parse_columns::<i64>(&generate(40, seed)).unwrap();
+            let (mut lhs, mut rhs) = (columns[0].clone(), columns[1].clone());
+            let assignment = optimal_pairing(&lhs, &rhs, &|l: i64, r: i64| l.abs_diff(r)).unwrap();
+            assert!(assignment.rhs_for.iter().all_unique());
+            lhs.sort_unstable();
+            rhs.sort_unstable();
+            assert_eq!(assignment.cost, distance_by(&lhs, &rhs, &Absolute));
+        }
+    }
+
+    #[test]
+    fn optimal_beats_sorted_for_other_costs() {
+        // Rewards pairing values that are far apart, where sorting gives the worst pairing.
+        let far = |l: i64, r: i64| 100 - l.abs_diff(r);
+        let (lhs, rhs) = ([1, 2, 3, 9, 4], [5, 8, 1, 7, 3]);
+        let best = (0..rhs.len())
+            .permutations(rhs.len())
+            .map(|p| (0..lhs.len()).map(|i| far(lhs[i], rhs[p[i]])).sum::<u64>())
+            .min()
+            .unwrap();
+        let assignment = optimal_pairing(&lhs, &rhs, &far).unwrap();
+        assert_eq!(assignment.cost, best);
+        assert!(assignment.cost < distance_by(&[1, 2, 3, 4, 9], &[1, 3, 5, 7, 8], &far));
+        assert!(optimal_pairing(&lhs, &rhs[1..], &far).is_err());
+    }
+
+    #[test]
+    fn ragged_columns() {
+        let input = ["1 2", "3"].map(String::from);
+        assert!(parse_columns::<usize>(&input).is_err());
+        assert!(problem1_solution(&["1 2 3".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn sorted_merge_matches_counts() {
+        for (len, seed) in [(0, 1), (1, 2), (500, 3), (5000, 4)] {
+            let (mut lhs, mut rhs) = parse_input(&generate(len, seed)).unwrap();
+            let expected = similarity_counts(&lhs, &rhs);
+            lhs.sort_unstable();
+            rhs.sort_unstable();
+            assert_eq!(similarity_sorted(&lhs, &rhs), expected);
+        }
+        assert_eq!(
+            similarity_sorted(&[1, 3, 3, 7], &[0, 3, 3, 3, 7, 8]),
+            3 * 3 * 2 + 7
+        );
+    }
+}
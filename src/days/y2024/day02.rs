@@ -0,0 +1,758 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use crate::{days::Part, options};
+
+/// Read by `SafetyPolicy::from_options`.
+pub const OPTIONS: [&str; 5] = [
+    "min-step",
+    "max-step",
+    "allow-equal",
+    "direction",
+    "tolerance",
+];
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
+
+fn problem1_solution(input: &[String]) -> Result<usize> {
+    let policy = SafetyPolicy {
+        tolerance: 0,
+        ..SafetyPolicy::from_options()?
+    };
+    let ns = parse_input(input);
+    Ok(ns
+        .into_iter()
+        .filter(|row| removals(row, &policy).is_some())
+        .count())
+}
+
+fn problem2_solution(input: &[String]) -> Result<usize> {
+    let policy = SafetyPolicy::from_options()?;
+    let ns = parse_input(input);
+    Ok(ns
+        .into_iter()
+        .filter(|row| removals(row, &policy).is_some())
+        .count())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    Either,
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "increasing" => Ok(Direction::Increasing),
+            "decreasing" => Ok(Direction::Decreasing),
+            "either" => Ok(Direction::Either),
+            _ => Err(anyhow!("unknown direction '{}'", s)),
+        }
+    }
+}
+
+/// What makes a report safe. The default is the puzzle's: levels that all increase or all
+/// decrease by 1 to 3, with the Problem Dampener removing at most one level. The fields are
+/// private so that every policy has been checked by `new`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafetyPolicy {
+    min_step: isize,
+    max_step: isize,
+    /// Whether adjacent levels may be equal, whatever `min_step` is.
+    allow_equal: bool,
+    direction: Direction,
+    /// How many levels may be removed.
+    tolerance: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            allow_equal: false,
+            direction: Direction::Either,
+            tolerance: 1,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// A policy allowing steps of `min_step` to `max_step` between adjacent levels, which must
+    /// be at least 1 since equal levels are allowed by `allow_equal` instead.
+    pub fn new(
+        min_step: isize,
+        max_step: isize,
+        allow_equal: bool,
+        direction: Direction,
+        tolerance: usize,
+    ) -> Result<Self> {
+        if min_step < 1 {
+            return Err(anyhow!(
+                "--min-step must be at least 1; use --allow-equal true to allow equal levels"
+            ));
+        }
+        if min_step > max_step {
+            return Err(anyhow!("--min-step is more than --max-step"));
+        }
+        Ok(SafetyPolicy {
+            min_step,
+            max_step,
+            allow_equal,
+            direction,
+            tolerance,
+        })
+    }
+
+    /// The default policy with any of `--min-step`, `--max-step`, `--allow-equal`, `--direction`
+    /// and `--tolerance` applied.
+    pub fn from_options() -> Result<Self> {
+        let default = SafetyPolicy::default();
+        SafetyPolicy::new(
+            options::get("min-step")?.unwrap_or(default.min_step),
+            options::get("max-step")?.unwrap_or(default.max_step),
+            options::get("allow-equal")?.unwrap_or(default.allow_equal),
+            options::get("direction")?.unwrap_or(default.direction),
+            options::get("tolerance")?.unwrap_or(default.tolerance),
+        )
+    }
+
+    /// Whether `step`, taken in the direction of travel, is allowed.
+    fn allows(&self, step: isize) -> bool {
+        match step {
+            0 => self.allow_equal,
+            _ => (self.min_step..=self.max_step).contains(&step),
+        }
+    }
+}
+
+/// The fewest levels to remove to make `row` safe, if that is no more than the policy's
+/// tolerance.
+pub fn min_removals(row: &[isize], policy: &SafetyPolicy) -> Option<usize> {
+    removals(row, policy).map(|removed| removed.len())
+}
+
+/// The indices of the fewest levels to remove to make `row` safe, if that is no more than the
+/// policy's tolerance.
+///
+/// For each direction, finds the longest safe run of kept levels ending at each level from the
+/// longest run so far ending at each value a step away, so this takes O(n·s) time for `s`
+/// allowed steps, whatever the tolerance.
+pub fn removals(row: &[isize], policy: &SafetyPolicy) -> Option<Vec<usize>> {
+    let increasing =
+        (policy.direction != Direction::Decreasing).then(|| directed_removals(row, policy, 1));
+    let decreasing =
+        (policy.direction != Direction::Increasing).then(|| directed_removals(row, policy, -1));
+    increasing
+        .into_iter()
+        .chain(decreasing)
+        .flatten()
+        .min_by_key(Vec::len)
+}
+
+fn directed_removals(row: &[isize], policy: &SafetyPolicy, sign: isize) -> Option<Vec<usize>> {
+    let steps = policy
+        .allow_equal
+        .then_some(0)
+        .into_iter()
+        .chain(policy.min_step..=policy.max_step);
+    let step_count = (policy.max_step - policy.min_step) as usize + 1 + policy.allow_equal as usize;
+    // runs[v] is the longest safe run so far ending at a level of value v, and where it ends;
+    // previous[i] is the level kept before row[i] in the longest run ending there.
+    let mut runs: HashMap<isize, (usize, usize)> = HashMap::new();
+    let mut previous = Vec::with_capacity(row.len());
+    let mut longest = (0, 0);
+    // Ties go to the earliest level, so the removals don't depend on the map's order.
+    let key = |&(len, end): &(usize, usize)| (len, Reverse(end));
+    for (i, &level) in row.iter().enumerate() {
+        let before = if step_count <= runs.len() {
+            steps
+                .clone()
+                .filter_map(|step| runs.get(&(level - sign * step)))
+                .max_by_key(|run| key(run))
+        } else {
+            runs.iter()
+                .filter(|(&value, _)| policy.allows(sign * (level - value)))
+                .map(|(_, run)| run)
+                .max_by_key(|run| key(run))
+        };
+        let run = before.map_or((1, i), |&(len, _)| (len + 1, i));
+        previous.push(before.map(|&(_, end)| end));
+        let best = runs.entry(level).or_insert(run);
+        if run.0 > best.0 {
+            *best = run;
+        }
+        if run.0 > longest.0 {
+            longest = run;
+        }
+    }
+    if row.len() - longest.0 > policy.tolerance {
+        return None;
+    }
+    let mut kept = vec![false; row.len()];
+    let mut level = (longest.0 > 0).then_some(longest.1);
+    while let Some(i) = level {
+        kept[i] = true;
+        level = previous[i];
+    }
+    Some((0..row.len()).filter(|&i| !kept[i]).collect())
+}
+
+/// Whether the levels pushed so far make a safe report, as is or with the policy's tolerance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Status {
+    pub safe: bool,
+    pub dampened: bool,
+}
+
+/// Checks a report one level at a time, such as from a live log. It runs the same DP as
+/// `removals`, keeping only the last `tolerance + 1` levels, so each push takes O(tolerance)
+/// time: O(1) for the puzzle's single removal.
+pub struct Monitor {
+    policy: SafetyPolicy,
+    len: usize,
+    runs: Vec<Run>,
+    status: Status,
+}
+
+/// The DP for one direction.
+struct Run {
+    increasing: bool,
+    /// The index, level and fewest removals before it for the most recent levels.
+    recent: VecDeque<(usize, isize, usize)>,
+    /// The least of each level's removals before it less its index, so that removing every level
+    /// after index `j` costs `removed[j] + len - 1 - j`.
+    best: isize,
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        Monitor::new(SafetyPolicy::default())
+    }
+}
+
+impl Monitor {
+    pub fn new(policy: SafetyPolicy) -> Self {
+        let runs = [true, false]
+            .into_iter()
+            .filter(|&increasing| match policy.direction {
+                Direction::Increasing => increasing,
+                Direction::Decreasing => !increasing,
+                Direction::Either => true,
+            })
+            .map(|increasing| Run {
+                increasing,
+                recent: VecDeque::with_capacity(policy.tolerance + 1),
+                best: isize::MAX,
+            })
+            .collect();
+        Monitor {
+            policy,
+            len: 0,
+            runs,
+            status: Status {
+                safe: true,
+                dampened: true,
+            },
+        }
+    }
+
+    pub fn push(&mut self, level: isize) -> Status {
+        let Monitor {
+            policy, len, runs, ..
+        } = self;
+        let i = *len;
+        *len += 1;
+        let mut fewest = usize::MAX;
+        for run in runs {
+            let removed = run
+                .recent
+                .iter()
+                .filter(|&&(_, before, _)| {
+                    policy.allows(if run.increasing {
+                        level - before
+                    } else {
+                        before - level
+                    })
+                })
+                .map(|&(j, _, removed)| removed + (i - j - 1))
+                .fold(i, usize::min);
+            if run.recent.len() > policy.tolerance {
+                run.recent.pop_front();
+            }
+            run.recent.push_back((i, level, removed));
+            run.best = run.best.min(removed as isize - i as isize);
+            fewest = fewest.min((i as isize + run.best) as usize);
+        }
+        self.status = Status {
+            safe: fewest == 0,
+            dampened: fewest <= policy.tolerance,
+        };
+        self.status
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Reason {
+    DirectionChange,
+    StepTooLarge,
+    StepTooSmall,
+    ZeroStep,
+}
+
+/// The first pair of levels, `index` and `index + 1`, that makes a report unsafe.
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    pub index: usize,
+    pub reason: Reason,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    Dampened {
+        violation: Violation,
+        removed: Vec<usize>,
+    },
+    Unsafe {
+        violation: Violation,
+    },
+}
+
+/// Says why `row` is or isn't safe under `policy`.
+pub fn explain(row: &[isize], policy: &SafetyPolicy) -> Verdict {
+    let Some(violation) = first_violation(row, policy) else {
+        return Verdict::Safe;
+    };
+    match removals(row, policy) {
+        Some(removed) => Verdict::Dampened { violation, removed },
+        None => Verdict::Unsafe { violation },
+    }
+}
+
+/// Unless the policy fixes the direction, it is set by the first pair of unequal levels, so a
+/// later pair going the other way changes it.
+fn first_violation(row: &[isize], policy: &SafetyPolicy) -> Option<Violation> {
+    let steps = || row.iter().tuple_windows().map(|(a, b)| b - a);
+    let direction = match policy.direction {
+        Direction::Increasing => 1,
+        Direction::Decreasing => -1,
+        Direction::Either => steps().map(isize::signum).find(|&d| d != 0).unwrap_or(0),
+    };
+    steps().enumerate().find_map(|(index, step)| {
+        let reason = if step == 0 {
+            if policy.allow_equal {
+                return None;
+            }
+            Reason::ZeroStep
+        } else if step.signum() != direction {
+            Reason::DirectionChange
+        } else if step.abs() > policy.max_step {
+            Reason::StepTooLarge
+        } else if step.abs() < policy.min_step {
+            Reason::StepTooSmall
+        } else {
+            return None;
+        };
+        Some(Violation { index, reason })
+    })
+}
+
+/// Explains each report under the same policy as part 2.
+pub fn visualize(input: &[String]) -> Result<String> {
+    let policy = SafetyPolicy::from_options()?;
+    let rows = parse_input(input);
+    Ok(rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let verdict = match explain(row, &policy) {
+                Verdict::Safe => "safe".to_owned(),
+                Verdict::Dampened { violation, removed } => format!(
+                    "{}, safe after removing {} {}",
+                    describe(row, &violation),
+                    if removed.len() == 1 {
+                        "index"
+                    } else {
+                        "indices"
+                    },
+                    removed.iter().join(", ")
+                ),
+                Verdict::Unsafe { violation } => describe(row, &violation),
+            };
+            format!("{:>4}  {:<24}  {}", i + 1, row.iter().join(" "), verdict)
+        })
+        .join("\n"))
+}
+
+fn describe(row: &[isize], violation: &Violation) -> String {
+    let reason = match violation.reason {
+        Reason::DirectionChange => "direction change",
+        Reason::StepTooLarge => "step too large",
+        Reason::StepTooSmall => "step too small",
+        Reason::ZeroStep => "step of zero",
+    };
+    let i = violation.index;
+    format!(
+        "unsafe at indices {}-{} ({} {}): {}",
+        i,
+        i + 1,
+        row[i],
+        row[i + 1],
+        reason
+    )
+}
+
+fn parse_input(input: &[String]) -> Vec<Vec<isize>> {
+    input
+        .iter()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|num| num.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Lcg;
+    use std::iter;
+
+    const INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    fn tolerating(tolerance: usize) -> SafetyPolicy {
+        SafetyPolicy {
+            tolerance,
+            ..SafetyPolicy::default()
+        }
+    }
+
+    fn solve_with(options: &[(&str, &str)]) -> Result<(usize, usize)> {
+        let input = load_test_data();
+        let options = options
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        options::with(&OPTIONS, options, || {
+            Ok((problem1_solution(&input)?, problem2_solution(&input)?))
+        })
+    }
+
+    /// The puzzle's check, the reference for `removals` with the default policy.
+    fn is_safe(row: &[isize]) -> bool {
+        row.iter()
+            .tuple_windows()
+            .all(|(a, b)| (1..=3).contains(&(b - a)))
+            || row
+                .iter()
+                .tuple_windows()
+                .all(|(a, b)| (1..=3).contains(&(a - b)))
+    }
+
+    fn is_subset_safe(row: &[isize], temp: &mut Vec<isize>) -> bool {
+        for i in 0..row.len() {
+            temp.clear();
+            temp.extend_from_slice(&row[0..i]);
+            temp.extend_from_slice(&row[(i + 1)..row.len()]);
+            if is_safe(temp) {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 4);
+    }
+
+    #[test]
+    fn removals() {
+        let rows = parse_input(&load_test_data());
+        let exact = rows
+            .iter()
+            .map(|row| min_removals(row, &tolerating(row.len())))
+            .collect_vec();
+        assert_eq!(
+            exact,
+            [Some(0), Some(2), Some(2), Some(1), Some(1), Some(0)]
+        );
+        assert_eq!(min_removals(&rows[1], &tolerating(1)), None);
+        assert_eq!(min_removals(&[], &tolerating(0)), Some(0));
+        assert_eq!(min_removals(&[1, 9, 2, 9, 3], &tolerating(1)), None);
+        assert_eq!(min_removals(&[1, 9, 2, 9, 3], &tolerating(2)), Some(2));
+    }
+
+    #[test]
+    fn tolerance_one_matches_rebuilding_rows() {
+        let mut temp = Vec::new();
+        for row in random_reports(7).take(2000) {
+            let dampened = is_safe(&row) || is_subset_safe(&row, &mut temp);
+            let safe = |tolerance| min_removals(&row, &tolerating(tolerance)).is_some();
+            assert_eq!(safe(1), dampened, "{:?}", row);
+            assert_eq!(safe(0), is_safe(&row), "{:?}", row);
+        }
+    }
+
+    #[test]
+    fn removals_are_minimal_for_any_policy() {
+        let policies = [
+            tolerating(8),
+            SafetyPolicy {
+                allow_equal: true,
+                ..tolerating(8)
+            },
+            SafetyPolicy {
+                min_step: 2,
+                max_step: 5,
+                direction: Direction::Decreasing,
+                ..tolerating(8)
+            },
+        ];
+        for (policy, row) in policies
+            .iter()
+            .cartesian_product(random_reports(13).take(300).collect_vec())
+        {
+            let safe = |kept: &[isize]| {
+                [1, -1].iter().any(|&sign| {
+                    kept.iter()
+                        .tuple_windows()
+                        .all(|(a, b)| policy.allows(sign * (b - a)))
+                        && (policy.direction != Direction::Increasing || sign == 1)
+                        && (policy.direction != Direction::Decreasing || sign == -1)
+                })
+            };
+            let fewest = (0..=row.len())
+                .find(|&count| {
+                    (0..row.len())
+                        .combinations(count)
+                        .any(|removed| safe(&without(&row, &removed)))
+                })
+                .unwrap();
+            let removed = super::removals(&row, policy).unwrap();
+            assert_eq!(removed.len(), fewest, "{:?} {:?}", policy, row);
+            assert!(safe(&without(&row, &removed)), "{:?} {:?}", policy, row);
+        }
+    }
+
+    fn without(row: &[isize], removed: &[usize]) -> Vec<isize> {
+        (0..row.len())
+            .filter(|i| !removed.contains(i))
+            .map(|i| row[i])
+            .collect()
+    }
+
+    #[test]
+    fn exact_removals_on_long_reports() {
+        let row = (0..100_000).map(|i| i / 2 * 3 + i % 2 * 10).collect_vec();
+        let removed = super::removals(&row, &tolerating(row.len())).unwrap();
+        assert_eq!(removed.len(), 50_000);
+    }
+
+    fn random_reports(seed: u64) -> impl Iterator<Item = Vec<isize>> {
+        let mut lcg = Lcg::new(seed);
+        iter::repeat_with(move || {
+            let len = 1 + lcg.below(8) as usize;
+            (0..len).map(|_| lcg.below(10) as isize).collect_vec()
+        })
+    }
+
+    #[test]
+    fn monitor_matches_whole_reports() {
+        let mut temp = Vec::new();
+        for row in random_reports(11).take(2000) {
+            let mut monitor = Monitor::default();
+            for (i, &level) in row.iter().enumerate() {
+                let prefix = &row[..=i];
+                let safe = is_safe(prefix);
+                let status = monitor.push(level);
+                assert_eq!(status.safe, safe, "{:?}", prefix);
+                assert_eq!(
+                    status.dampened,
+                    safe || is_subset_safe(prefix, &mut temp),
+                    "{:?}",
+                    prefix
+                );
+            }
+            assert_eq!(monitor.status().safe, is_safe(&row));
+        }
+    }
+
+    #[test]
+    fn monitor_follows_policy() {
+        let policy = SafetyPolicy {
+            max_step: 4,
+            direction: Direction::Increasing,
+            ..tolerating(2)
+        };
+        for row in random_reports(12).take(1000) {
+            let mut monitor = Monitor::new(policy.clone());
+            row.iter().for_each(|&level| {
+                monitor.push(level);
+            });
+            let removed = min_removals(&row, &policy);
+            assert_eq!(monitor.status().safe, removed == Some(0), "{:?}", row);
+            assert_eq!(monitor.status().dampened, removed.is_some(), "{:?}", row);
+        }
+    }
+
+    #[test]
+    fn explains_reports() {
+        let rows = parse_input(&load_test_data());
+        let violation = |index, reason| Violation { index, reason };
+        let verdicts = rows
+            .iter()
+            .map(|row| explain(row, &tolerating(1)))
+            .collect_vec();
+        assert_eq!(
+            verdicts,
+            [
+                Verdict::Safe,
+                Verdict::Unsafe {
+                    violation: violation(1, Reason::StepTooLarge)
+                },
+                Verdict::Unsafe {
+                    violation: violation(2, Reason::StepTooLarge)
+                },
+                Verdict::Dampened {
+                    violation: violation(1, Reason::DirectionChange),
+                    removed: vec![2]
+                },
+                Verdict::Dampened {
+                    violation: violation(2, Reason::ZeroStep),
+                    removed: vec![3]
+                },
+                Verdict::Safe,
+            ]
+        );
+        assert_eq!(
+            explain(&rows[1], &tolerating(2)),
+            Verdict::Dampened {
+                violation: violation(1, Reason::StepTooLarge),
+                removed: vec![0, 1]
+            }
+        );
+        assert_eq!(
+            visualize(&load_test_data()).unwrap().lines().nth(3).unwrap(),
+            "   4  1 3 2 4 5                 unsafe at indices 1-2 (3 2): direction change, safe after removing index 2"
+        );
+    }
+
+    #[test]
+    fn tolerance_option() {
+        assert_eq!(solve_with(&[("tolerance", "0")]).unwrap(), (2, 2));
+        assert_eq!(solve_with(&[("tolerance", "3")]).unwrap(), (2, 6));
+    }
+
+    #[test]
+    fn step_options() {
+        // Allowing steps up to 5 makes 1 2 7 8 9 and 9 7 6 2 1 safe.
+        assert_eq!(solve_with(&[("max-step", "5")]).unwrap(), (4, 6));
+        // With steps of at least 2, only 1 3 6 7 9 without the 7 and 8 6 4 4 1 without a 4 are
+        // safe.
+        assert_eq!(
+            solve_with(&[("min-step", "2"), ("tolerance", "0")]).unwrap(),
+            (0, 0)
+        );
+        assert_eq!(solve_with(&[("min-step", "2")]).unwrap(), (0, 2));
+        assert!(solve_with(&[("min-step", "0")]).is_err());
+        assert!(solve_with(&[("min-step", "4")]).is_err());
+        assert!(SafetyPolicy::new(0, 3, true, Direction::Either, 1).is_err());
+        assert!(SafetyPolicy::new(4, 3, false, Direction::Either, 1).is_err());
+        assert!(SafetyPolicy::new(2, 2, false, Direction::Increasing, 0).is_ok());
+        let policy = SafetyPolicy {
+            min_step: 2,
+            ..tolerating(0)
+        };
+        assert_eq!(
+            explain(&[1, 3, 4], &policy),
+            Verdict::Unsafe {
+                violation: Violation {
+                    index: 1,
+                    reason: Reason::StepTooSmall
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn allow_equal_option() {
+        // 8 6 4 4 1 becomes safe as it is.
+        assert_eq!(solve_with(&[("allow-equal", "true")]).unwrap(), (3, 4));
+        let policy = SafetyPolicy {
+            allow_equal: true,
+            ..tolerating(0)
+        };
+        assert_eq!(
+            explain(&[3, 3, 4, 4, 2], &policy),
+            Verdict::Unsafe {
+                violation: Violation {
+                    index: 3,
+                    reason: Reason::DirectionChange
+                }
+            }
+        );
+        assert_eq!(min_removals(&[5, 5, 5], &policy), Some(0));
+    }
+
+    #[test]
+    fn direction_option() {
+        assert_eq!(solve_with(&[("direction", "increasing")]).unwrap(), (1, 2));
+        assert_eq!(solve_with(&[("direction", "decreasing")]).unwrap(), (1, 2));
+        assert_eq!(solve_with(&[("direction", "either")]).unwrap(), (2, 4));
+        assert!(solve_with(&[("direction", "sideways")]).is_err());
+        assert!(solve_with(&[("tolerence", "2")]).is_err());
+        let policy = SafetyPolicy {
+            direction: Direction::Decreasing,
+            ..tolerating(0)
+        };
+        assert_eq!(
+            explain(&[1, 2, 3], &policy),
+            Verdict::Unsafe {
+                violation: Violation {
+                    index: 0,
+                    reason: Reason::DirectionChange
+                }
+            }
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use anyhow::Result;
+
+use crate::days::Part;
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
+
+fn problem1_solution(input: &[String]) -> Result<isize> {
+    Ok(input
+        .iter()
+        .flat_map(|line| lex(line))
+        .map(|token| match token.instruction {
+            Instruction::Mul(x, y) => x * y,
+            _ => 0,
+        })
+        .sum())
+}
+
+fn problem2_solution(input: &[String]) -> Result<isize> {
+    let mut machine = Machine::default();
+    for token in input.iter().flat_map(|line| lex(line)) {
+        machine.execute(&token.instruction);
+    }
+    Ok(machine.total)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Instruction {
+    Mul(isize, isize),
+    Do,
+    Dont,
+    /// A well-formed call to anything else.
+    Unknown {
+        name: String,
+        args: Vec<isize>,
+    },
+}
+
+impl Instruction {
+    /// The instruction a call to `name` with `args` is, if it is one the machine knows.
+    fn from_call(name: &str, args: &[isize]) -> Option<Instruction> {
+        match (name, args) {
+            ("mul", &[x, y]) => Some(Instruction::Mul(x, y)),
+            ("do", []) => Some(Instruction::Do),
+            ("don't", []) => Some(Instruction::Dont),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Token {
+    /// The byte offset of the instruction's name in the source.
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+/// Finds every call in corrupted memory: a name, then up to three digits for each of any number
+/// of comma-separated arguments in parentheses. Memory is corrupted right up to a known name, so
+/// `xmul(2,4)` is a `mul` at the `m`, while a name no suffix of which is known is `Unknown`.
+pub fn lex(source: &str) -> Vec<Token> {
+    let bytes = source.as_bytes();
+    let is_name = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'\'';
+    let mut tokens = Vec::new();
+    for (open, _) in source.match_indices('(') {
+        let Some(args) = parse_args(&source[open + 1..]) else {
+            continue;
+        };
+        let start = bytes[..open]
+            .iter()
+            .rposition(|&b| !is_name(b))
+            .map_or(0, |i| i + 1);
+        if start == open {
+            continue;
+        }
+        let token = (start..open)
+            .find_map(|offset| {
+                let instruction = Instruction::from_call(&source[offset..open], &args)?;
+                Some(Token {
+                    offset,
+                    instruction,
+                })
+            })
+            .unwrap_or_else(|| Token {
+                offset: start,
+                instruction: Instruction::Unknown {
+                    name: source[start..open].to_owned(),
+                    args,
+                },
+            });
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Parses `1,22,333)` at the start of `rest`. Scanning stops at the first byte that can't be
+/// part of the arguments, so lexing looks at each byte after a `(` at most once.
+fn parse_args(rest: &str) -> Option<Vec<isize>> {
+    let len = rest
+        .bytes()
+        .position(|b| !b.is_ascii_digit() && b != b',')
+        .filter(|&len| rest.as_bytes()[len] == b')')?;
+    let args = &rest[..len];
+    if args.is_empty() {
+        return Some(Vec::new());
+    }
+    args.split(',')
+        .map(|arg| match arg.len() {
+            1..=3 => arg.parse().ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The interpreter's state: whether `mul` is enabled, and the sum of the products so far.
+pub struct Machine {
+    pub enabled: bool,
+    pub total: isize,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine {
+            enabled: true,
+            total: 0,
+        }
+    }
+}
+
+impl Machine {
+    pub fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Mul(x, y) => {
+                if self.enabled {
+                    self.total += x * y;
+                }
+            }
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = false,
+            Instruction::Unknown { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT1: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const INPUT2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn load_test_data(input: &str) -> Vec<String> {
+        input.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data(INPUT1)).unwrap();
+        assert_eq!(answer, 161);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data(INPUT2)).unwrap();
+        assert_eq!(answer, 48);
+    }
+
+    #[test]
+    fn lexes_with_offsets() {
+        let token = |offset, instruction| Token {
+            offset,
+            instruction,
+        };
+        assert_eq!(
+            lex(INPUT2),
+            [
+                token(1, Instruction::Mul(2, 4)),
+                token(20, Instruction::Dont),
+                token(28, Instruction::Mul(5, 5)),
+                token(48, Instruction::Mul(11, 8)),
+                token(59, Instruction::Do),
+                token(64, Instruction::Mul(8, 5)),
+            ]
+        );
+        assert_eq!(
+            lex("what()mul(1234,5)from(7,12)mul(1,2,3)mul(,)"),
+            [
+                token(
+                    0,
+                    Instruction::Unknown {
+                        name: "what".to_owned(),
+                        args: vec![]
+                    }
+                ),
+                token(
+                    17,
+                    Instruction::Unknown {
+                        name: "from".to_owned(),
+                        args: vec![7, 12]
+                    }
+                ),
+                token(
+                    27,
+                    Instruction::Unknown {
+                        name: "mul".to_owned(),
+                        args: vec![1, 2, 3]
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn lexes_long_corruption() {
+        let source = format!("{}mul(2,3)", "mul(1,".repeat(100_000));
+        assert_eq!(
+            lex(&source),
+            [Token {
+                offset: 600_000,
+                instruction: Instruction::Mul(2, 3)
+            }]
+        );
+    }
+
+    #[test]
+    fn machine_state() {
+        let mut machine = Machine::default();
+        machine.execute(&Instruction::Mul(2, 3));
+        machine.execute(&Instruction::Dont);
+        machine.execute(&Instruction::Mul(5, 5));
+        assert!(!machine.enabled);
+        machine.execute(&Instruction::Do);
+        machine.execute(&Instruction::Mul(1, 4));
+        assert_eq!(machine.total, 10);
+    }
+}
@@ -1,22 +1,16 @@
 use anyhow::Result;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Error},
-    path::Path,
-};
+use std::collections::HashSet;
 
-const INPUT_FILE: &str = concat!("./data/", env!("CARGO_BIN_NAME"), ".txt");
+use crate::days::Part;
 
-fn main() -> Result<()> {
-    let input_file = File::open(Path::new(INPUT_FILE))?;
-    let lines: Vec<String> = BufReader::new(input_file)
-        .lines()
-        .collect::<Result<Vec<String>, Error>>()?;
-
-    println!("problem1 = {}", problem1_solution(&lines)?);
-    println!("problem2 = {}", problem2_solution(&lines)?);
-    Ok(())
-}
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
 
 const DIRECTIONS: [[isize; 2]; 8] = [
     [1, 0],
@@ -29,7 +23,7 @@ const DIRECTIONS: [[isize; 2]; 8] = [
     [1, -1],
 ];
 
-fn problem1_solution(input: &Vec<String>) -> Result<usize> {
+fn problem1_solution(input: &[String]) -> Result<usize> {
     let mut total = 0;
     for y in 0..input.len() {
         for x in 0..input[y].len() {
@@ -59,12 +53,55 @@ fn problem1_solution(input: &Vec<String>) -> Result<usize> {
     Ok(total)
 }
 
-fn char_at(input: &Vec<String>, y: usize, x: usize) -> Option<u8> {
+/// The letter grid with the letters of every XMAS match in upper case and the rest in
+/// lower case.
+pub fn grid(input: &[String]) -> Result<Vec<String>> {
+    let mut matched = HashSet::new();
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            for d in DIRECTIONS.iter() {
+                let cells = (0..4)
+                    .map(|i| {
+                        (
+                            y.wrapping_add_signed(i * d[0]),
+                            x.wrapping_add_signed(i * d[1]),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if cells
+                    .iter()
+                    .zip(b"XMAS")
+                    .all(|(&(y, x), &c)| char_at(input, y, x) == Some(c))
+                {
+                    matched.extend(cells);
+                }
+            }
+        }
+    }
+    Ok(input
+        .iter()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    if matched.contains(&(y, x)) {
+                        c.to_ascii_uppercase()
+                    } else {
+                        c.to_ascii_lowercase()
+                    }
+                })
+                .collect()
+        })
+        .collect())
+}
+
+fn char_at(input: &[String], y: usize, x: usize) -> Option<u8> {
     let row = input.get(y)?;
     row.as_bytes().get(x).cloned()
 }
 
-fn problem2_solution(input: &Vec<String>) -> Result<usize> {
+fn problem2_solution(input: &[String]) -> Result<usize> {
     let mut total = 0;
     for y in 0..input.len() {
         for x in 0..input[y].len() {
@@ -99,8 +136,9 @@ fn problem2_solution(input: &Vec<String>) -> Result<usize> {
 mod tests {
 
     use super::*;
+    use itertools::Itertools;
 
-    const INPUT: &'static str = "MMMSXXMASM
+    const INPUT: &str = "MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -126,4 +164,24 @@ MXMXAXMASX";
         let answer = problem2_solution(&load_test_data()).unwrap();
         assert_eq!(answer, 9);
     }
+
+    #[test]
+    fn grid_highlights_matches() {
+        let expected = "....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX";
+        let grid = grid(&load_test_data()).unwrap();
+        let matched = grid
+            .iter()
+            .map(|row| row.replace(|c: char| c.is_ascii_lowercase(), "."))
+            .join("\n");
+        assert_eq!(matched, expected);
+    }
 }
@@ -1,26 +1,19 @@
 use anyhow::Result;
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Error},
-    path::Path,
-};
-
-const INPUT_FILE: &str = concat!("./data/", env!("CARGO_BIN_NAME"), ".txt");
-
-fn main() -> Result<()> {
-    let input_file = File::open(Path::new(INPUT_FILE))?;
-    let lines: Vec<String> = BufReader::new(input_file)
-        .lines()
-        .collect::<Result<Vec<String>, Error>>()?;
-
-    println!("problem1 = {}", problem1_solution(&lines)?);
-    println!("problem2 = {}", problem2_solution(&lines)?);
-    Ok(())
-}
 
-fn problem1_solution(input: &Vec<String>) -> Result<usize> {
+use crate::days::Part;
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input)?.into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input)?.into())
+    }),
+];
+
+fn problem1_solution(input: &[String]) -> Result<usize> {
     let (pairs, updates) = parse_input(input);
 
     Ok(updates
@@ -30,7 +23,7 @@ fn problem1_solution(input: &Vec<String>) -> Result<usize> {
         .sum())
 }
 
-fn problem2_solution(input: &Vec<String>) -> Result<usize> {
+fn problem2_solution(input: &[String]) -> Result<usize> {
     let (pairs, updates) = parse_input(input);
 
     Ok(updates
@@ -41,7 +34,9 @@ fn problem2_solution(input: &Vec<String>) -> Result<usize> {
         .sum())
 }
 
-fn parse_input(input: &Vec<String>) -> (HashMap<(&str, &str), usize>, Vec<Vec<&str>>) {
+type Pairs<'a> = HashMap<(&'a str, &'a str), usize>;
+
+fn parse_input(input: &[String]) -> (Pairs<'_>, Vec<Vec<&str>>) {
     let mut lines = input.iter();
     let pairs = lines
         .by_ref()
@@ -68,7 +63,7 @@ fn sort<'a>(pairs: &HashMap<(&str, &str), usize>, mut update: Vec<&'a str>) -> V
     update
 }
 
-fn is_ordered(pairs: &HashMap<(&str, &str), usize>, update: &Vec<&str>) -> bool {
+fn is_ordered(pairs: &HashMap<(&str, &str), usize>, update: &[&str]) -> bool {
     !update
         .iter()
         .tuple_combinations()
@@ -80,7 +75,7 @@ mod tests {
 
     use super::*;
 
-    const INPUT: &'static str = "47|53
+    const INPUT: &str = "47|53
 97|13
 97|61
 97|47
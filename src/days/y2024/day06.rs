@@ -0,0 +1,235 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use nalgebra::Vector2;
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::days::Part;
+use crate::svg::{self, Svg};
+use crate::{animation, progress};
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "path_length", |input| {
+        path_length(input, None)
+            .map(Answer::from)
+            .ok_or_else(|| anyhow!("the guard never leaves the map"))
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input).into())
+    }),
+];
+
+const DIRECTIONS: [[isize; 2]; 4] = [[1, 0], [0, 1], [-1, 0], [0, -1]];
+
+const DIRECTION_CHARS: [char; 4] = ['>', 'v', '<', '^'];
+
+fn path_length(input: &[String], obstacle: Option<(usize, usize)>) -> Option<usize> {
+    guard_path(input, obstacle).map(|route| route.visited.len())
+}
+
+struct Route {
+    visited: HashSet<(usize, usize)>,
+    /// Where the guard starts, turns and leaves, in order.
+    corners: Vec<(usize, usize)>,
+}
+
+fn guard_path(input: &[String], obstacle: Option<(usize, usize)>) -> Option<Route> {
+    let (mut y, start_line) = input
+        .iter()
+        .find_position(|line| line.contains(DIRECTION_CHARS))
+        .unwrap();
+    let mut x = start_line.find(DIRECTION_CHARS).unwrap();
+    let mut d = DIRECTION_CHARS
+        .iter()
+        .position(|c| start_line.chars().contains(c))
+        .unwrap();
+    let max_x = input.iter().map(|line| line.len()).max().unwrap();
+    let mut visited = HashSet::new();
+    let mut states = HashSet::new();
+    let mut corners = vec![(x, y)];
+    loop {
+        if !states.insert((x, y, d)) {
+            return None;
+        }
+        visited.insert((x, y));
+        animation::frame(|| draw(input, &visited, Some((x, y, d)), obstacle));
+        let next_x = x.wrapping_add_signed(DIRECTIONS[d][0]);
+        let next_y = y.wrapping_add_signed(DIRECTIONS[d][1]);
+        if next_y >= input.len() || next_x >= max_x {
+            corners.push((x, y));
+            return Some(Route { visited, corners });
+        }
+        if &input[next_y][next_x..=next_x] == "#" || obstacle == Some((next_x, next_y)) {
+            corners.push((x, y));
+            d = (d + 1) & 3;
+        } else {
+            x = next_x;
+            y = next_y;
+        }
+    }
+}
+
+fn problem2_solution(input: &[String]) -> usize {
+    let mut count = 0;
+    for (obstacle_y, line) in input.iter().enumerate() {
+        progress::report(obstacle_y, input.len());
+        for obstacle_x in 0..line.len() {
+            if &line[obstacle_x..=obstacle_x] == "."
+                && path_length(input, Some((obstacle_x, obstacle_y))).is_none()
+            {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Draws the map with every cell the guard visits marked `X`.
+pub fn visualize(input: &[String]) -> Result<String> {
+    let route = guard_path(input, None).ok_or_else(|| anyhow!("the guard never leaves the map"))?;
+    Ok(draw(input, &route.visited, None, None))
+}
+
+/// Draws the obstacles and the guard's route as a polyline from a dot at the start.
+pub fn svg(input: &[String]) -> Result<String> {
+    let route = guard_path(input, None).ok_or_else(|| anyhow!("the guard never leaves the map"))?;
+    let max_x = input.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut svg = Svg::new(max_x, input.len(), 8.0);
+    svg.grid("#333");
+    for (y, line) in input.iter().enumerate() {
+        for x in line.match_indices('#').map(|(x, _)| x) {
+            svg.cell(Vector2::new(x as isize, y as isize), "#888", None);
+        }
+    }
+    let corners = route
+        .corners
+        .iter()
+        .map(|&(x, y)| Svg::centre(Vector2::new(x as isize, y as isize)))
+        .collect::<Vec<_>>();
+    svg.polyline(&corners, &svg::colour(0), 0.3)
+        .point(corners[0], 0.4, &svg::colour(1));
+    Ok(svg.to_string())
+}
+
+pub fn grid(input: &[String]) -> Result<Vec<String>> {
+    Ok(visualize(input)?.lines().map(str::to_owned).collect())
+}
+
+fn draw(
+    input: &[String],
+    visited: &HashSet<(usize, usize)>,
+    guard: Option<(usize, usize, usize)>,
+    obstacle: Option<(usize, usize)>,
+) -> String {
+    input
+        .iter()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| match guard {
+                    Some((gx, gy, d)) if (gx, gy) == (x, y) => DIRECTION_CHARS[d],
+                    _ if obstacle == Some((x, y)) => 'O',
+                    _ if visited.contains(&(x, y)) => 'X',
+                    _ => c,
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = path_length(&load_test_data(), None).unwrap();
+        assert_eq!(answer, 41);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data());
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn visualization() {
+        let picture = visualize(&load_test_data()).unwrap();
+        assert_eq!(
+            picture,
+            "....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X.."
+        );
+    }
+
+    #[test]
+    fn route_corners() {
+        let route = guard_path(&load_test_data(), None).unwrap();
+        assert_eq!(
+            route.corners,
+            [
+                (4, 6),
+                (4, 1),
+                (8, 1),
+                (8, 6),
+                (2, 6),
+                (2, 4),
+                (6, 4),
+                (6, 8),
+                (1, 8),
+                (1, 7),
+                (7, 7),
+                (7, 9)
+            ]
+        );
+        let svg = svg(&load_test_data()).unwrap();
+        assert!(svg.contains(r#"<polyline points="36,52 36,12 68,12 68,52 "#));
+        assert_eq!(svg.matches(r##"fill="#888""##).count(), 8);
+    }
+
+    #[test]
+    fn animation() {
+        let recorder = animation::Recorder::new(1, 100);
+        let (_, frames) = recorder.record(|| path_length(&load_test_data(), None));
+        assert_eq!(frames.len(), 55);
+        assert_eq!(
+            frames[5],
+            "....#.....
+....^....#
+....X.....
+..#.X.....
+....X..#..
+....X.....
+.#..X.....
+........#.
+#.........
+......#..."
+        );
+    }
+}
@@ -1,25 +1,17 @@
-use anyhow::Result;
 use itertools::Itertools;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Error},
-    path::Path,
-};
 
-const INPUT_FILE: &str = concat!("./data/", env!("CARGO_BIN_NAME"), ".txt");
+use crate::days::Part;
 
-fn main() -> Result<()> {
-    let input_file = File::open(Path::new(INPUT_FILE))?;
-    let lines: Vec<String> = BufReader::new(input_file)
-        .lines()
-        .collect::<Result<Vec<String>, Error>>()?;
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem_solution", |input| {
+        Ok(problem_solution(input, false).into())
+    }),
+    Part::new(2, "problem_solution", |input| {
+        Ok(problem_solution(input, true).into())
+    }),
+];
 
-    println!("problem1 = {}", problem_solution(&lines, false));
-    println!("problem2 = {}", problem_solution(&lines, true));
-    Ok(())
-}
-
-fn problem_solution(input: &Vec<String>, allow_concat: bool) -> usize {
+fn problem_solution(input: &[String], allow_concat: bool) -> usize {
     input
         .iter()
         .filter_map(|line| {
@@ -56,7 +48,7 @@ mod tests {
 
     use super::*;
 
-    const INPUT: &'static str = "190: 10 19
+    const INPUT: &str = "190: 10 19
 3267: 81 40 27
 83: 17 5
 156: 15 6
@@ -0,0 +1,212 @@
+use anyhow::Result;
+use itertools::Itertools;
+use nalgebra::Vector2;
+
+use crate::days::Part;
+use crate::svg::{self, Svg};
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem_solution", |input| {
+        Ok(problem_solution(input, false).into())
+    }),
+    Part::new(2, "problem_solution", |input| {
+        Ok(problem_solution(input, true).into())
+    }),
+];
+
+fn problem_solution(input: &[String], part2: bool) -> usize {
+    antinodes(input, part2).len()
+}
+
+/// The antenna map with every resonant-harmonics antinode not covered by an antenna marked `#`.
+/// Antinodes past the end of a short row are left out.
+pub fn grid(input: &[String]) -> Result<Vec<String>> {
+    let mut grid = input
+        .iter()
+        .map(|line| line.clone().into_bytes())
+        .collect::<Vec<_>>();
+    for p in antinodes(input, true) {
+        if let Some(cell) = grid[p.y as usize].get_mut(p.x as usize) {
+            if *cell == b'.' {
+                *cell = b'#';
+            }
+        }
+    }
+    Ok(grid
+        .into_iter()
+        .map(|row| String::from_utf8_lossy(&row).into_owned())
+        .collect())
+}
+
+/// Draws each frequency's antennas, the lines through each pair and their antinodes in the
+/// frequency's own colour.
+pub fn svg(input: &[String]) -> Result<String> {
+    let (max_x, max_y) = bounds(input);
+    let mut svg = Svg::new(max_x, max_y, 16.0);
+    svg.grid("#333");
+    for (i, (frequency, positions)) in antennas(input).iter().enumerate() {
+        let colour = svg::colour(i);
+        let mut locations = Vec::new();
+        for (a, b) in positions.iter().tuple_combinations() {
+            pair_antinodes(a, b, true, max_x, max_y, &mut locations);
+            svg.line(Svg::centre(*a), Svg::centre(*b), &colour, 0.08);
+        }
+        locations.sort_by_key(|p| (p.y, p.x));
+        locations.dedup();
+        for p in locations {
+            svg.point(Svg::centre(p), 0.2, &colour);
+        }
+        for p in positions {
+            svg.cell(*p, &colour, Some(&(*frequency as char).to_string()));
+        }
+    }
+    Ok(svg.to_string())
+}
+
+fn antinodes(input: &[String], part2: bool) -> Vec<Vector2<isize>> {
+    let (max_x, max_y) = bounds(input);
+    let mut locations = Vec::new();
+    for (_, positions) in antennas(input) {
+        for (a, b) in positions.iter().tuple_combinations() {
+            pair_antinodes(a, b, part2, max_x, max_y, &mut locations);
+        }
+    }
+    locations.sort_by_key(|p| (p.y, p.x));
+    locations.dedup();
+    locations
+}
+
+fn bounds(input: &[String]) -> (usize, usize) {
+    let max_x = input.iter().map(|line| line.len()).max().unwrap_or(0);
+    (max_x, input.len())
+}
+
+/// The positions of every frequency's antennas, for the frequencies that have any.
+fn antennas(input: &[String]) -> Vec<(u8, Vec<Vector2<isize>>)> {
+    let mut antennas = Vec::new();
+    for b in (b'0'..=b'9').chain(b'A'..=b'Z').chain(b'a'..=b'z') {
+        let mut positions = Vec::new();
+        for (y, line) in input.iter().enumerate() {
+            positions.extend(
+                line.as_bytes()
+                    .iter()
+                    .positions(|&c| c == b)
+                    .map(|x| Vector2::from([x as isize, y as isize])),
+            );
+        }
+        if !positions.is_empty() {
+            antennas.push((b, positions));
+        }
+    }
+    antennas
+}
+
+fn pair_antinodes(
+    a: &Vector2<isize>,
+    b: &Vector2<isize>,
+    part2: bool,
+    max_x: usize,
+    max_y: usize,
+    locations: &mut Vec<Vector2<isize>>,
+) {
+    let diff = b - a;
+    if part2 {
+        let mut p = *a;
+        while is_in_range(&p, max_x, max_y) {
+            locations.push(p);
+            p -= diff;
+        }
+        p = *b;
+        while is_in_range(&p, max_x, max_y) {
+            locations.push(p);
+            p += diff;
+        }
+    } else {
+        locations.extend(
+            [a - diff, b + diff]
+                .into_iter()
+                .filter(|p| is_in_range(p, max_x, max_y)),
+        );
+    }
+}
+
+fn is_in_range(p: &Vector2<isize>, max_x: usize, max_y: usize) -> bool {
+    (0..max_x).contains(&(p.x as usize)) && (0..max_y).contains(&(p.y as usize))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem_solution(&load_test_data(), false);
+        assert_eq!(answer, 14);
+    }
+
+    #[test]
+    fn problem2() {
+        let input = &load_test_data();
+        let answer = problem_solution(input, true);
+        assert_eq!(answer, 34);
+    }
+
+    #[test]
+    fn grid_marks_antinodes() {
+        let expected = "##....#....#
+.#.#....0...
+..#.#0....#.
+..##...0....
+....0....#..
+.#...#A....#
+...#..#.....
+#....#.#....
+..#.....A...
+....#....A..
+.#........#.
+...#......##";
+        assert_eq!(grid(&load_test_data()).unwrap().join("\n"), expected);
+    }
+
+    #[test]
+    fn grid_skips_antinodes_past_short_rows() {
+        // The antennas' line continues through (2, 3), which is inside the widest row but past
+        // the end of the last.
+        for input in [["...", "a..", ".a.", "."], ["....", "a...", ".a", ""]] {
+            let input = input.map(String::from);
+            assert_eq!(grid(&input).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn svg_draws_each_frequency() {
+        let svg = svg(&load_test_data()).unwrap();
+        // Four '0' antennas make six pairs and three 'A' antennas make three pairs with
+        // 16 antinodes between them.
+        assert_eq!(svg.matches("<line").count(), 13 + 13 + 6 + 3);
+        assert_eq!(svg.matches(">0</text>").count(), 4);
+        assert_eq!(
+            svg.matches(&format!("fill=\"{}\"/>", svg::colour(1)))
+                .count(),
+            3 + 16
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::Result;
+
+use crate::animation;
+use crate::days::Part;
+
+pub const PARTS: [Part; 2] = [
+    Part::new(1, "problem1_solution", |input| {
+        Ok(problem1_solution(input).into())
+    }),
+    Part::new(2, "problem2_solution", |input| {
+        Ok(problem2_solution(input).into())
+    }),
+];
+
+fn problem1_solution(input: &[String]) -> usize {
+    let mut blocks = disk_blocks(input);
+    compact_blocks(&mut blocks);
+    blocks
+        .iter()
+        .map_while(|&block| block)
+        .enumerate()
+        .map(|(i, id)| i * id)
+        .sum()
+}
+
+fn disk_blocks(input: &[String]) -> Vec<Option<usize>> {
+    let mut blocks = Vec::new();
+    let mut is_gap: bool = false;
+    let mut i: usize = 0;
+    for b in input[0].as_bytes() {
+        let len = b - b'0';
+        let block = if is_gap {
+            i += 1;
+            None
+        } else {
+            Some(i)
+        };
+        blocks.extend((0..len).map(|_| block));
+        is_gap = !is_gap;
+    }
+    blocks
+}
+
+fn compact_blocks(blocks: &mut Vec<Option<usize>>) {
+    let disk_len = blocks.len();
+    animation::frame(|| render(blocks, disk_len));
+    let mut i = blocks.len() - 1;
+    let mut gap_index = 0;
+    while gap_index < i {
+        if blocks[gap_index].is_some() {
+            gap_index += 1;
+        } else {
+            blocks[gap_index] = blocks.remove(i);
+            animation::frame(|| render(blocks, disk_len));
+            i = blocks
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(ii, cc)| cc.map(|_| ii))
+                .unwrap_or_default();
+        }
+    }
+}
+
+fn problem2_solution(input: &[String]) -> usize {
+    let (mut files, mut gaps) = disk_files(input);
+    compact_files(&mut files, &mut gaps);
+    files
+        .iter()
+        .enumerate()
+        .flat_map(|(id, &f)| (f.0..(f.0 + f.1)).map(move |i| i * id))
+        .sum()
+}
+
+type Ranges = Vec<(usize, usize)>;
+
+fn disk_files(input: &[String]) -> (Ranges, Ranges) {
+    let mut files = Vec::new();
+    let mut gaps = Vec::new();
+    let mut is_gap: bool = false;
+    let mut i: usize = 0;
+    for b in input[0].as_bytes() {
+        let len = (b - b'0') as usize;
+        let range = (i, len);
+        i += len;
+        if is_gap {
+            gaps.push(range);
+        } else {
+            files.push(range);
+        };
+        is_gap = !is_gap;
+    }
+    (files, gaps)
+}
+
+fn compact_files(files: &mut Ranges, gaps: &mut Ranges) {
+    let disk_len = files
+        .iter()
+        .chain(gaps.iter())
+        .map(|r| r.0 + r.1)
+        .max()
+        .unwrap_or_default();
+    animation::frame(|| render(&file_blocks(files, disk_len), disk_len));
+    for id in (0..files.len()).rev() {
+        let f = &mut files[id];
+        if let Some(gap_index) = gaps.iter().position(|gap| gap.1 >= f.1) {
+            let gap = &mut gaps[gap_index];
+            if gap.0 < f.0 {
+                f.0 = gap.0;
+                gap.0 += f.1;
+                gap.1 -= f.1;
+                animation::frame(|| render(&file_blocks(files, disk_len), disk_len));
+            }
+        }
+    }
+}
+
+fn file_blocks(files: &Ranges, disk_len: usize) -> Vec<Option<usize>> {
+    let mut blocks = vec![None; disk_len];
+    for (id, &(start, len)) in files.iter().enumerate() {
+        blocks[start..start + len].fill(Some(id));
+    }
+    blocks
+}
+
+/// Draws the disk before compaction and after each part's compaction, with
+/// each block shown as the last digit of its file id and free space as `.`.
+pub fn visualize(input: &[String]) -> Result<String> {
+    let before = disk_blocks(input);
+
+    let mut blocks = before.clone();
+    compact_blocks(&mut blocks);
+
+    let (mut files, mut gaps) = disk_files(input);
+    compact_files(&mut files, &mut gaps);
+
+    Ok(format!(
+        "disk map\n{}\n\nafter moving blocks\n{}\n\nafter moving whole files\n{}",
+        render(&before, before.len()),
+        render(&blocks, before.len()),
+        render(&file_blocks(&files, before.len()), before.len())
+    ))
+}
+
+/// Renders the first `disk_len` blocks, treating any beyond the end of `blocks` as free space.
+fn render(blocks: &[Option<usize>], disk_len: usize) -> String {
+    let mut blocks = blocks.to_vec();
+    blocks.resize(disk_len, None);
+    blocks
+        .chunks(100)
+        .map(|row| {
+            row.iter()
+                .map(|block| match block {
+                    Some(id) => char::from(b'0' + (id % 10) as u8),
+                    None => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "2333133121414131402";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data());
+        assert_eq!(answer, 1928);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data());
+        assert_eq!(answer, 2858);
+    }
+
+    #[test]
+    fn visualization() {
+        let picture = visualize(&load_test_data()).unwrap();
+        assert_eq!(
+            picture,
+            "disk map
+00...111...2...333.44.5555.6666.777.888899
+
+after moving blocks
+0099811188827773336446555566..............
+
+after moving whole files
+00992111777.44.333....5555.6666.....8888.."
+        );
+    }
+
+    #[test]
+    fn animation() {
+        let recorder = animation::Recorder::new(1, 100);
+        let (_, frames) = recorder.record(|| problem2_solution(&load_test_data()));
+        assert_eq!(
+            frames,
+            vec![
+                "00...111...2...333.44.5555.6666.777.888899",
+                "0099.111...2...333.44.5555.6666.777.8888..",
+                "0099.1117772...333.44.5555.6666.....8888..",
+                "0099.111777244.333....5555.6666.....8888..",
+                "00992111777.44.333....5555.6666.....8888..",
+            ]
+        );
+    }
+}
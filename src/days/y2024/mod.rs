@@ -0,0 +1,33 @@
+use super::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+
+pub const DAYS: [Day; 9] = [
+    Day::new(2024, 1, include_str!("day01.rs"), &day01::PARTS)
+        .with_visualization(day01::visualize)
+        .with_stream(day01::stream)
+        .with_options(&day01::OPTIONS),
+    Day::new(2024, 2, include_str!("day02.rs"), &day02::PARTS)
+        .with_visualization(day02::visualize)
+        .with_options(&day02::OPTIONS),
+    Day::new(2024, 3, include_str!("day03.rs"), &day03::PARTS),
+    Day::new(2024, 4, include_str!("day04.rs"), &day04::PARTS).with_grid(day04::grid),
+    Day::new(2024, 5, include_str!("day05.rs"), &day05::PARTS),
+    Day::new(2024, 6, include_str!("day06.rs"), &day06::PARTS)
+        .with_visualization(day06::visualize)
+        .with_grid(day06::grid)
+        .with_svg(day06::svg),
+    Day::new(2024, 7, include_str!("day07.rs"), &day07::PARTS),
+    Day::new(2024, 8, include_str!("day08.rs"), &day08::PARTS)
+        .with_grid(day08::grid)
+        .with_svg(day08::svg),
+    Day::new(2024, 9, include_str!("day09.rs"), &day09::PARTS).with_visualization(day09::visualize),
+];
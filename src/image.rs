@@ -0,0 +1,198 @@
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+/// An RGB raster, usually drawn from a grid of cells with one colour per cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each character of `rows` as a `scale`×`scale` block coloured by `palette`.
+    /// Short rows are padded with the colour of a space.
+    pub fn from_grid(rows: &[String], scale: usize, palette: impl Fn(char) -> Rgb) -> Image {
+        let scale = scale.max(1);
+        let columns = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let width = columns * scale;
+        let mut pixels = Vec::with_capacity(width * rows.len() * scale);
+        for row in rows {
+            let line = row
+                .chars()
+                .chain(std::iter::repeat(' '))
+                .take(columns)
+                .flat_map(|c| std::iter::repeat_n(palette(c), scale))
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image {
+            width,
+            height: rows.len() * scale,
+            pixels,
+        }
+    }
+
+    /// Writes a binary (P6) PPM.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    /// Writes an 8-bit RGB PNG whose image data uses uncompressed (stored) deflate blocks.
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_chunk(&mut out, b"IHDR", &header)?;
+
+        let mut scanlines = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flatten());
+        }
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&scanlines))?;
+        write_chunk(&mut out, b"IEND", &[])
+    }
+}
+
+/// Colours `.` and spaces dark, `#` grey, `X` yellow, upper-case letters white and
+/// lower-case letters dim, and gives each digit its own colour.
+pub fn default_palette(c: char) -> Rgb {
+    match c {
+        '.' | ' ' => [16, 16, 32],
+        '#' => [128, 128, 128],
+        'X' => [255, 204, 0],
+        'O' => [220, 40, 40],
+        '0'..='9' => {
+            let hue = c as u8 - b'0';
+            [60 + hue * 20, 255 - hue * 20, 120 + hue * 10]
+        }
+        'A'..='Z' => [255, 255, 255],
+        'a'..='z' => [80, 80, 100],
+        _ => [0, 160, 255],
+    }
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream made of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        out.push(blocks.peek().is_none() as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(!0u32, |crc, &b| {
+        (0..8).fold(crc ^ b as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Image {
+        let rows = vec!["#.".to_owned(), "X".to_owned()];
+        Image::from_grid(&rows, 1, default_palette)
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn scaling() {
+        let rows = vec!["#.".to_owned()];
+        let image = Image::from_grid(&rows, 2, default_palette);
+        assert_eq!((image.width, image.height), (4, 2));
+        assert_eq!(
+            image.pixels[..4],
+            [[128; 3], [128; 3], [16, 16, 32], [16, 16, 32]]
+        );
+        assert_eq!(image.pixels[4..], image.pixels[..4]);
+    }
+
+    #[test]
+    fn ppm_golden() {
+        let mut out = Vec::new();
+        grid().write_ppm(&mut out).unwrap();
+        assert_eq!(
+            out,
+            b"P6\n2 2\n255\n\x80\x80\x80\x10\x10\x20\xff\xcc\x00\x10\x10\x20"
+        );
+    }
+
+    #[test]
+    fn png_golden() {
+        let mut out = Vec::new();
+        grid().write_png(&mut out).unwrap();
+        let expected: &[u8] = &[
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, // signature
+            0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52, // IHDR
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00, 0x00, 0xfd,
+            0xd4, 0x9a, 0x73, // IHDR crc
+            0x00, 0x00, 0x00, 0x19, 0x49, 0x44, 0x41, 0x54, // IDAT
+            0x78, 0x01, 0x01, 0x0e, 0x00, 0xf1, 0xff, // zlib header and stored block
+            0x00, 0x80, 0x80, 0x80, 0x10, 0x10, 0x20, // row 1
+            0x00, 0xff, 0xcc, 0x00, 0x10, 0x10, 0x20, // row 2
+            0x1e, 0xa4, 0x03, 0xcc, // adler32
+            0x32, 0x0d, 0x1a, 0x5f, // IDAT crc
+            0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, // IEND
+            0xae, 0x42, 0x60, 0x82, // IEND crc
+        ];
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn png_splits_large_images_into_blocks() {
+        let zlib = zlib_stored(&vec![7; 70000]);
+        assert_eq!(zlib[2], 0);
+        assert_eq!(zlib[2 + 5 + 65535], 1);
+        assert_eq!(zlib.len(), 2 + 5 + 65535 + 5 + 4465 + 4);
+    }
+}
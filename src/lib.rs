@@ -0,0 +1,68 @@
+use anyhow::{Context, Result};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Error},
+    path::{Path, PathBuf},
+};
+
+pub mod animation;
+pub mod answer;
+pub mod cache;
+pub mod config;
+pub mod days;
+pub mod image;
+pub mod migrate;
+pub mod options;
+pub mod progress;
+pub mod report;
+pub mod runner;
+pub mod serve;
+pub mod svg;
+#[cfg(test)]
+mod testing;
+pub mod verify;
+pub mod watch;
+
+/// The year commands use when `--year` is not given.
+pub const DEFAULT_YEAR: u32 = 2024;
+
+pub fn day_name(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+pub fn input_path(data_dir: &Path, year: u32, day: u32) -> PathBuf {
+    data_dir
+        .join(year.to_string())
+        .join(format!("{}.txt", day_name(day)))
+}
+
+pub fn read_input(data_dir: &Path, year: u32, day: u32) -> Result<Vec<String>> {
+    let path = input_path(data_dir, year, day);
+    let input_file = File::open(&path).with_context(|| {
+        let legacy = input_path(Path::new(config::LEGACY_DATA_DIR), year, day);
+        if !path.exists() && legacy.exists() {
+            format!(
+                "failed to open {}; run `aoc migrate-inputs` to move {} there",
+                path.display(),
+                legacy.display()
+            )
+        } else {
+            format!("failed to open {}", path.display())
+        }
+    })?;
+    Ok(BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?)
+}
+
+/// Reads a real puzzle input for a test, or returns `None` so the test can skip when the input
+/// has not been downloaded.
+#[cfg(test)]
+pub(crate) fn test_input(year: u32, day: u32) -> Option<Vec<String>> {
+    let data_dir = config::Config::load().ok()?.data_dir;
+    let input = read_input(&data_dir, year, day).ok();
+    if input.is_none() {
+        eprintln!("skipping: no input for {} {}", year, day_name(day));
+    }
+    input
+}
@@ -0,0 +1,127 @@
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Migration {
+    pub moved: Vec<(PathBuf, PathBuf)>,
+    /// Inputs left where they were because the destination already had one.
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Moves the inputs in `from` to `to`. Inputs in year directories (`from/YYYY/dayNN.txt`) keep
+/// their year, and loose ones (`from/dayNN.txt`) are taken to be `year`'s. Directories left empty
+/// are removed.
+pub fn migrate_inputs(from: &Path, to: &Path, year: u32) -> Result<Migration> {
+    let mut migration = Migration::default();
+    let mut dirs = vec![(from.to_path_buf(), year)];
+    for entry in fs::read_dir(from).with_context(|| format!("failed to read {}", from.display()))? {
+        let path = entry?.path();
+        let year = path.file_name().and_then(|n| n.to_str()?.parse().ok());
+        if let (true, Some(year)) = (path.is_dir(), year) {
+            dirs.push((path, year));
+        }
+    }
+    for (dir, year) in dirs.iter().rev() {
+        let mut inputs = fs::read_dir(dir)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?;
+        inputs.retain(|path| is_input(path));
+        inputs.sort();
+        for input in inputs {
+            let target = to.join(year.to_string()).join(input.file_name().unwrap());
+            if target.exists() {
+                migration.skipped.push(input);
+                continue;
+            }
+            move_file(&input, &target)?;
+            migration.moved.push((input, target));
+        }
+        // Only succeeds once the directory is empty.
+        let _ = fs::remove_dir(dir);
+    }
+    Ok(migration)
+}
+
+fn is_input(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    path.is_file() && name.starts_with("day") && name.ends_with(".txt")
+}
+
+fn move_file(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to.parent().unwrap())?;
+    // Renaming fails across filesystems, where copying is the only way.
+    if fs::rename(from, to).is_err() {
+        fs::copy(from, to)
+            .with_context(|| format!("failed to copy {} to {}", from.display(), to.display()))?;
+        fs::remove_file(from)?;
+    }
+    Ok(())
+}
+
+/// Adds `entry` to the `.gitignore` at `path` unless it is already there, returning whether it
+/// was added.
+pub fn ignore(path: &Path, entry: &str) -> Result<bool> {
+    let mut gitignore = fs::read_to_string(path).unwrap_or_default();
+    if gitignore.lines().any(|line| line.trim() == entry) {
+        return Ok(false);
+    }
+    if !gitignore.is_empty() && !gitignore.ends_with('\n') {
+        gitignore.push('\n');
+    }
+    gitignore.push_str(entry);
+    gitignore.push('\n');
+    fs::write(path, gitignore)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn moves_loose_and_year_inputs() {
+        let dir = temp_dir("migrate");
+        let (from, to) = (dir.join("data"), dir.join("cache"));
+        fs::create_dir_all(from.join("2023")).unwrap();
+        fs::create_dir_all(to.join("2024")).unwrap();
+        fs::write(from.join("day01.txt"), "1").unwrap();
+        fs::write(from.join("day02.txt"), "new").unwrap();
+        fs::write(from.join("2023").join("day01.txt"), "2").unwrap();
+        fs::write(from.join("notes.md"), "").unwrap();
+        fs::write(to.join("2024").join("day02.txt"), "old").unwrap();
+
+        let migration = migrate_inputs(&from, &to, 2024).unwrap();
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(migration.moved.len(), 2);
+        assert_eq!(migration.skipped, [from.join("day02.txt")]);
+        assert_eq!(read(to.join("2024").join("day01.txt")), "1");
+        assert_eq!(read(to.join("2023").join("day01.txt")), "2");
+        assert_eq!(read(to.join("2024").join("day02.txt")), "old");
+        assert!(!from.join("2023").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn ignores_once() {
+        let dir = temp_dir("gitignore");
+        let path = dir.join(".gitignore");
+        fs::write(&path, "target/").unwrap();
+        assert!(ignore(&path, "/data/").unwrap());
+        assert!(!ignore(&path, "/data/").unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "target/\n/data/\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::{anyhow, Result};
+use std::{cell::RefCell, collections::BTreeMap, str::FromStr};
+
+thread_local! {
+    static OPTIONS: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// The value of the day-specific option `name` given to the solution running on this thread, such
+/// as day 2's `--tolerance`, or `None` when it wasn't given.
+pub fn get<T: FromStr>(name: &str) -> Result<Option<T>> {
+    OPTIONS.with(|options| {
+        let options = options.borrow();
+        let Some(value) = options.get(name) else {
+            return Ok(None);
+        };
+        value
+            .parse()
+            .map(Some)
+            .map_err(|_| anyhow!("invalid value '{}' for --{}", value, name))
+    })
+}
+
+/// Parses `--name value` pairs, and `--name` alone as `--name true`.
+pub fn parse(args: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut options = BTreeMap::new();
+    let mut args = args.iter().peekable();
+    while let Some(arg) = args.next() {
+        let name = arg
+            .strip_prefix("--")
+            .ok_or_else(|| anyhow!("expected --option [value], found '{}'", arg))?;
+        let value = args.next_if(|value| !value.starts_with("--"));
+        options.insert(
+            name.to_owned(),
+            value.map_or("true".to_owned(), Clone::clone),
+        );
+    }
+    Ok(options)
+}
+
+/// Runs `f` on this thread with `options` visible to `get`, first failing if any of them is not
+/// one of the `accepted` names, which is most likely a typo.
+pub fn with<T>(
+    accepted: &[&str],
+    options: BTreeMap<String, String>,
+    f: impl FnOnce() -> Result<T>,
+) -> Result<T> {
+    if let Some(name) = options
+        .keys()
+        .find(|name| !accepted.contains(&name.as_str()))
+    {
+        return Err(match accepted {
+            [] => anyhow!("unknown option --{}; this day takes no options", name),
+            _ => anyhow!(
+                "unknown option --{}; expected one of --{}",
+                name,
+                accepted.join(", --")
+            ),
+        });
+    }
+    OPTIONS.with(|current| *current.borrow_mut() = options);
+    let result = f();
+    OPTIONS.with(|current| current.take());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_pairs() {
+        let options = parse(&args(&["--tolerance", "2"])).unwrap();
+        assert_eq!(options["tolerance"], "2");
+        assert!(parse(&args(&["tolerance", "2"])).is_err());
+        let flags = parse(&args(&["--optimal", "--top", "-1", "--allow-equal"])).unwrap();
+        assert_eq!(flags["optimal"], "true");
+        assert_eq!(flags["top"], "-1");
+        assert_eq!(flags["allow-equal"], "true");
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid_options() {
+        let options = || parse(&args(&["--tolerance", "2"])).unwrap();
+        let tolerance = with(&["tolerance"], options(), || get::<usize>("tolerance")).unwrap();
+        assert_eq!(tolerance, Some(2));
+        assert_eq!(get::<usize>("tolerance").unwrap(), None);
+        let mut ran = false;
+        let unknown = with(&["tolerence"], options(), || {
+            ran = true;
+            Ok(())
+        });
+        assert!(unknown.is_err() && !ran);
+        assert!(with(&["tolerance"], options(), || get::<bool>("tolerance")).is_err());
+    }
+}
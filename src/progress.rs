@@ -0,0 +1,188 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    io::{self, IsTerminal, Write},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+const RENDER_INTERVAL: Duration = Duration::from_millis(100);
+
+thread_local! {
+    static TASK: RefCell<Option<(Arc<Progress>, usize)>> = const { RefCell::new(None) };
+    static LAST_REPORT: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Reports that `done` of `total` items have been processed by the solution running on this thread.
+/// This is a no-op unless the runner has enabled progress output.
+pub fn report(done: usize, total: usize) {
+    TASK.with(|task| {
+        if let Some((progress, id)) = task.borrow().as_ref() {
+            let now = Instant::now();
+            if LAST_REPORT
+                .get()
+                .is_some_and(|last| now - last < RENDER_INTERVAL)
+            {
+                return;
+            }
+            LAST_REPORT.set(Some(now));
+            progress.update(*id, done, total);
+        }
+    });
+}
+
+/// A single progress line on stderr, shared by all the parts currently running.
+pub struct Progress {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: usize,
+    tasks: BTreeMap<usize, Task>,
+    rendered: bool,
+}
+
+struct Task {
+    label: String,
+    started: Instant,
+    done: usize,
+    total: usize,
+}
+
+impl Progress {
+    /// Returns a renderer if stderr is a terminal.
+    pub fn for_stderr() -> Option<Arc<Progress>> {
+        io::stderr().is_terminal().then(|| {
+            Arc::new(Progress {
+                state: Mutex::new(State::default()),
+            })
+        })
+    }
+
+    /// Runs `f` on the current thread with calls to [`report`] attributed to `label`.
+    pub fn track<T>(self: &Arc<Self>, label: String, f: impl FnOnce() -> T) -> T {
+        let id = {
+            let mut state = self.state.lock().unwrap();
+            let id = state.next_id;
+            state.next_id += 1;
+            state.tasks.insert(
+                id,
+                Task {
+                    label,
+                    started: Instant::now(),
+                    done: 0,
+                    total: 0,
+                },
+            );
+            id
+        };
+        TASK.with(|task| *task.borrow_mut() = Some((Arc::clone(self), id)));
+        LAST_REPORT.set(None);
+        let _tracking = Tracking { progress: self, id };
+        f()
+    }
+
+    /// Stops showing `label`, for a part that the runner has given up waiting for.
+    pub fn abandon(&self, label: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.tasks.retain(|_, task| task.label != label);
+        state.render();
+    }
+
+    fn update(&self, id: usize, done: usize, total: usize) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(task) = state.tasks.get_mut(&id) {
+            task.done = done;
+            task.total = total;
+            state.render();
+        }
+    }
+}
+
+/// Clears the thread's task when the tracked closure returns or panics.
+struct Tracking<'a> {
+    progress: &'a Progress,
+    id: usize,
+}
+
+impl Drop for Tracking<'_> {
+    fn drop(&mut self) {
+        TASK.with(|task| *task.borrow_mut() = None);
+        let mut state = self.progress.state.lock().unwrap();
+        state.tasks.remove(&self.id);
+        state.render();
+    }
+}
+
+impl State {
+    fn render(&mut self) {
+        let line = self
+            .tasks
+            .values()
+            .filter(|task| task.total > 0)
+            .map(|task| task.describe(task.started.elapsed()))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        if line.is_empty() && !self.rendered {
+            return;
+        }
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{}", line);
+        let _ = stderr.flush();
+        self.rendered = !line.is_empty();
+    }
+}
+
+impl Task {
+    fn describe(&self, elapsed: Duration) -> String {
+        let mut line = format!(
+            "{} {}/{} ({}%)",
+            self.label,
+            self.done,
+            self.total,
+            self.done.min(self.total) * 100 / self.total
+        );
+        if self.done > 0 {
+            let remaining = self.total.saturating_sub(self.done) as f64 / self.done as f64;
+            let remaining = elapsed.mul_f64(remaining);
+            line += &format!(" ETA {:.0?}", remaining);
+        }
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(done: usize, total: usize) -> Task {
+        Task {
+            label: "day06 part 2".to_owned(),
+            started: Instant::now(),
+            done,
+            total,
+        }
+    }
+
+    #[test]
+    fn describe_without_eta() {
+        assert_eq!(
+            task(0, 130).describe(Duration::from_secs(1)),
+            "day06 part 2 0/130 (0%)"
+        );
+    }
+
+    #[test]
+    fn describe_with_eta() {
+        assert_eq!(
+            task(25, 100).describe(Duration::from_secs(3)),
+            "day06 part 2 25/100 (25%) ETA 9s"
+        );
+    }
+
+    #[test]
+    fn report_without_tracking_is_ignored() {
+        report(1, 2);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    answer::Answer,
+    runner::{Outcome, RunResult},
+};
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(anyhow!("unknown format '{}', expected table or json", s)),
+        }
+    }
+}
+
+/// One line of `aoc run --format json` output, describing a single part.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartReport {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub implementation: String,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub time_ms: f64,
+    pub cached: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl From<&RunResult> for PartReport {
+    fn from(result: &RunResult) -> Self {
+        let (status, answer, error) = match &result.outcome {
+            Outcome::Answer(answer) => (Status::Ok, Some(answer.clone()), None),
+            Outcome::Error(e) => (Status::Error, None, Some(e.clone())),
+            Outcome::Panic(e) => (Status::Panic, None, Some(e.clone())),
+            Outcome::Timeout => (Status::Timeout, None, Some("timed out".to_owned())),
+        };
+        PartReport {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            implementation: result.name.to_owned(),
+            status,
+            answer,
+            time_ms: result.elapsed.as_secs_f64() * 1000.0,
+            cached: result.cached,
+            error,
+        }
+    }
+}
+
+pub fn print_json(results: &[RunResult]) -> serde_json::Result<()> {
+    for result in results {
+        println!("{}", serde_json::to_string(&PartReport::from(result))?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn round_trip() {
+        let result = RunResult {
+            year: 2024,
+            day: 6,
+            part: 2,
+            name: "problem2_solution",
+            outcome: Outcome::Answer(Answer::Unsigned(1831)),
+            elapsed: Duration::from_millis(1500),
+            cached: false,
+        };
+        let json = serde_json::to_string(&PartReport::from(&result)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2024,"day":6,"part":2,"implementation":"problem2_solution","status":"ok","answer":{"type":"unsigned","value":1831},"time_ms":1500.0,"cached":false,"error":null}"#
+        );
+        let report: PartReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(report, PartReport::from(&result));
+    }
+
+    #[test]
+    fn timeout() {
+        let result = RunResult {
+            year: 2024,
+            day: 7,
+            part: 1,
+            name: "problem_solution",
+            outcome: Outcome::Timeout,
+            elapsed: Duration::from_secs(60),
+            cached: false,
+        };
+        let report = PartReport::from(&result);
+        assert_eq!(report.status, Status::Timeout);
+        assert_eq!(report.answer, None);
+        assert_eq!(report.error.as_deref(), Some("timed out"));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    answer::Answer,
+    cache::{self, Cache, Key, CACHE_FILE},
+    config::{self, Config},
+    day_name,
+    days::{Day, Part},
+    progress::Progress,
+    read_input,
+};
+use anyhow::Result;
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+pub struct RunResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub name: &'static str,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Whether the answer was taken from the cache rather than computed.
+    pub cached: bool,
+}
+
+pub enum Outcome {
+    Answer(Answer),
+    Error(String),
+    Panic(String),
+    Timeout,
+}
+
+pub struct Options {
+    pub jobs: usize,
+    /// Time budget for each part, after which it is reported as a timeout and abandoned.
+    pub timeout: Duration,
+    /// Whether to show a progress line on stderr for solutions that report progress.
+    pub progress: bool,
+    /// Whether to reuse answers cached for an unchanged input and source.
+    pub cache: bool,
+    /// How many times to solve each part; the reported time is the mean.
+    pub iterations: usize,
+    pub data_dir: PathBuf,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(60),
+            progress: true,
+            cache: true,
+            iterations: 1,
+            data_dir: Config::default().data_dir,
+        }
+    }
+}
+
+impl RunResult {
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Answer(_))
+    }
+}
+
+/// Runs every part of `days` on up to `options.jobs` threads, returning the results in year, day
+/// and part order.
+pub fn run(days: &[&'static Day], options: &Options) -> Vec<RunResult> {
+    let load = |year, day| read_input(&options.data_dir, year, day);
+    if !options.cache {
+        return run_with(days, options, load, None);
+    }
+    let path = config::repository_root().join(CACHE_FILE);
+    let mut cache = Cache::load(&path);
+    let results = run_with(days, options, load, Some(&mut cache));
+    if let Err(e) = cache.save() {
+        eprintln!("failed to save {}: {:#}", path.display(), e);
+    }
+    results
+}
+
+fn run_with<F>(
+    days: &[&'static Day],
+    options: &Options,
+    load: F,
+    mut cache: Option<&mut Cache>,
+) -> Vec<RunResult>
+where
+    F: Fn(u32, u32) -> Result<Vec<String>>,
+{
+    let inputs = days
+        .iter()
+        .map(|day| {
+            load(day.year, day.day)
+                .map(Arc::new)
+                .map_err(|e| format!("{:#}", e))
+        })
+        .collect::<Vec<_>>();
+    let mut tasks = days
+        .iter()
+        .zip(inputs.iter())
+        .flat_map(|(day, input)| {
+            let input_hash = input.as_ref().ok().map(|lines| cache::hash_lines(lines));
+            let source_hash = cache::hash(day.source.as_bytes());
+            day.parts.iter().map(move |part| {
+                let key = input_hash.clone().map(|input_hash| Key {
+                    year: day.year,
+                    day: day.day,
+                    part: part.part,
+                    name: part.name.to_owned(),
+                    input_hash,
+                    source_hash: source_hash.clone(),
+                });
+                (*day, part, input, key)
+            })
+        })
+        .collect::<Vec<_>>();
+    tasks.sort_by_key(|(day, part, _, _)| (day.year, day.day, part.part));
+
+    let cached = tasks
+        .iter()
+        .map(|(day, part, _, key)| {
+            let answer = cache.as_deref()?.get(key.as_ref()?)?;
+            Some(RunResult {
+                year: day.year,
+                day: day.day,
+                part: part.part,
+                name: part.name,
+                outcome: Outcome::Answer(answer.clone()),
+                elapsed: Duration::ZERO,
+                cached: true,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let progress = options.progress.then(Progress::for_stderr).flatten();
+    let pending = (0..tasks.len())
+        .filter(|&i| cached[i].is_none())
+        .collect::<Vec<_>>();
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(cached);
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.clamp(1, pending.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&i) = pending.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let (day, part, input, _) = tasks[i];
+                    let result = run_part(day, part, input, options, progress.clone());
+                    results.lock().unwrap()[i] = Some(result);
+                }
+            });
+        }
+    });
+    let results = results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if let Some(cache) = cache.as_mut() {
+        for (result, (_, _, _, key)) in results.iter().zip(tasks) {
+            if let (Outcome::Answer(answer), false, Some(key)) =
+                (&result.outcome, result.cached, key)
+            {
+                cache.insert(key, answer.clone());
+            }
+        }
+    }
+    results
+}
+
+/// Solves one part on a detached thread so that a part which overruns its budget can be abandoned.
+/// An abandoned thread keeps running in the background until the process exits.
+fn run_part(
+    day: &Day,
+    part: &'static Part,
+    input: &Result<Arc<Vec<String>>, String>,
+    options: &Options,
+    progress: Option<Arc<Progress>>,
+) -> RunResult {
+    let (timeout, iterations) = (options.timeout, options.iterations.max(1));
+    let (outcome, elapsed) = match input {
+        Err(e) => (Outcome::Error(e.clone()), Duration::ZERO),
+        Ok(lines) => {
+            let lines = Arc::clone(lines);
+            let label = format!("{} {} part {}", day.year, day_name(day.day), part.part);
+            let (tx, rx) = mpsc::channel();
+            let tracker = progress.clone();
+            let tracked_label = label.clone();
+            thread::spawn(move || {
+                let start = Instant::now();
+                let solve = || {
+                    let mut answer = (part.solve)(&lines);
+                    for _ in 1..iterations {
+                        answer = (part.solve)(&lines);
+                    }
+                    answer
+                };
+                let solve = || match &progress {
+                    Some(progress) => progress.track(tracked_label, solve),
+                    None => solve(),
+                };
+                let outcome = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+                    Ok(Ok(answer)) => Outcome::Answer(answer),
+                    Ok(Err(e)) => Outcome::Error(format!("{:#}", e)),
+                    Err(payload) => Outcome::Panic(panic_message(payload)),
+                };
+                let _ = tx.send((outcome, start.elapsed() / iterations as u32));
+            });
+            rx.recv_timeout(timeout).unwrap_or_else(|_| {
+                if let Some(progress) = tracker {
+                    progress.abandon(&label);
+                }
+                (Outcome::Timeout, timeout)
+            })
+        }
+    };
+    RunResult {
+        year: day.year,
+        day: day.day,
+        part: part.part,
+        name: part.name,
+        outcome,
+        elapsed,
+        cached: false,
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+pub fn print_table(results: &[RunResult]) {
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  {:<7}  answer",
+        "year", "day", "part", "implementation", "time", "status"
+    );
+    for r in results {
+        let (status, answer) = match &r.outcome {
+            Outcome::Answer(answer) if r.cached => ("cached", answer.to_string()),
+            Outcome::Answer(answer) => ("ok", answer.to_string()),
+            Outcome::Error(e) => ("ERROR", e.clone()),
+            Outcome::Panic(e) => ("PANIC", e.clone()),
+            Outcome::Timeout => ("TIMEOUT", String::new()),
+        };
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  {:<7}  {}",
+            r.year,
+            r.day,
+            r.part,
+            r.name,
+            format!("{:.2?}", r.elapsed),
+            status,
+            answer
+        );
+    }
+    let failures = results.iter().filter(|r| !r.is_ok()).count();
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    println!(
+        "{} parts, {} failed, {:.2?} total cpu time",
+        results.len(),
+        failures,
+        total
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    const PARTS: [Part; 3] = [
+        Part::new(1, "count", |input| Ok(input.len().into())),
+        Part::new(2, "fails", |_| Err(anyhow!("no answer"))),
+        Part::new(3, "panics", |_| panic!("boom")),
+    ];
+
+    const SLOW: [Part; 1] = [Part::new(1, "sleeps", |_| {
+        thread::sleep(Duration::from_secs(10));
+        Ok("".into())
+    })];
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    const COUNTED: [Part; 1] = [Part::new(1, "counted", |_| {
+        Ok(CALLS.fetch_add(1, Ordering::Relaxed).into())
+    })];
+
+    const DAY1: Day = Day::new(2024, 1, "day 1", &PARTS);
+    const DAY2: Day = Day::new(2024, 2, "day 2", PARTS.split_at(1).0);
+    const DAY3: Day = Day::new(2024, 3, "day 3", &SLOW);
+    const DAY4: Day = Day::new(2024, 4, "day 4", &COUNTED);
+    const OLD_DAY2: Day = Day::new(2023, 2, "day 2", PARTS.split_at(1).0);
+
+    fn options(jobs: usize) -> Options {
+        Options {
+            jobs,
+            timeout: Duration::from_secs(5),
+            progress: false,
+            cache: false,
+            ..Options::default()
+        }
+    }
+
+    fn load(_year: u32, day: u32) -> Result<Vec<String>> {
+        Ok(vec![String::new(); day as usize])
+    }
+
+    #[test]
+    fn results_in_day_order() {
+        let results = run_with(&[&DAY2, &DAY1], &options(4), load, None);
+        let keys = results.iter().map(|r| (r.day, r.part)).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(1, 1), (1, 2), (1, 3), (2, 1)]);
+    }
+
+    #[test]
+    fn results_in_year_order() {
+        let results = run_with(&[&DAY2, &OLD_DAY2], &options(2), load, None);
+        let keys = results.iter().map(|r| (r.year, r.day)).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(2023, 2), (2024, 2)]);
+    }
+
+    #[test]
+    fn failures_do_not_stop_other_parts() {
+        let results = run_with(&[&DAY1, &DAY2], &options(2), load, None);
+        assert!(matches!(&results[0].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(1)));
+        assert!(matches!(&results[1].outcome, Outcome::Error(e) if e == "no answer"));
+        assert!(matches!(&results[2].outcome, Outcome::Panic(e) if e == "boom"));
+        assert!(matches!(&results[3].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(2)));
+    }
+
+    #[test]
+    fn iterations_repeat_each_part() {
+        let options = Options {
+            iterations: 3,
+            ..options(1)
+        };
+        let results = run_with(&[&DAY4], &options, load, None);
+        assert!(matches!(&results[0].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(2)));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn missing_input() {
+        let results = run_with(&[&DAY2], &options(1), |_, _| Err(anyhow!("missing")), None);
+        assert!(matches!(&results[0].outcome, Outcome::Error(e) if e == "missing"));
+    }
+
+    #[test]
+    fn timeout_does_not_block_other_days() {
+        let options = Options {
+            timeout: Duration::from_millis(50),
+            ..options(1)
+        };
+        let results = run_with(&[&DAY3, &DAY2], &options, load, None);
+        assert!(matches!(&results[0].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(2)));
+        assert!(matches!(results[1].outcome, Outcome::Timeout));
+    }
+
+    #[test]
+    fn cached_answers_are_reused() {
+        let mut cache = Cache::default();
+        let results = run_with(&[&DAY2], &options(1), load, Some(&mut cache));
+        assert!(!results[0].cached);
+
+        let results = run_with(&[&DAY2], &options(1), load, Some(&mut cache));
+        assert!(results[0].cached);
+        assert!(matches!(&results[0].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(2)));
+
+        let changed = |_, _| Ok(vec![String::new(); 3]);
+        let results = run_with(&[&DAY2], &options(1), changed, Some(&mut cache));
+        assert!(!results[0].cached);
+        assert!(matches!(&results[0].outcome, Outcome::Answer(a) if *a == Answer::Unsigned(3)));
+    }
+}
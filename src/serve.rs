@@ -0,0 +1,432 @@
+use crate::{
+    config::Config,
+    day_name,
+    days::{self, Day},
+    read_input,
+    runner::{self, Options, Outcome, RunResult},
+};
+use anyhow::Result;
+use std::{
+    collections::HashSet,
+    fmt::Write as _,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+/// How long a connection may take to send its request before it is dropped.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serves the dashboard for the configured year on the loopback interface until the process is
+/// stopped.
+pub fn serve(config: &Config, port: u16) -> Result<()> {
+    let days = days::year(config.year)?;
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!(
+        "serving {} on http://{}/",
+        config.year,
+        listener.local_addr()?
+    );
+    let port = listener.local_addr()?.port();
+    accept(listener, Arc::new(State::new(days, &config.data_dir, port)));
+    Ok(())
+}
+
+/// Handles each connection on its own thread, so a client that never sends its request can't
+/// hold up the others.
+fn accept(listener: TcpListener, state: Arc<State>) {
+    for stream in listener.incoming() {
+        let state = Arc::clone(&state);
+        thread::spawn(move || {
+            if let Err(e) = stream.map_err(Into::into).and_then(|s| handle(&state, s)) {
+                eprintln!("request failed: {:#}", e);
+            }
+        });
+    }
+}
+
+struct State {
+    days: &'static [Day],
+    data_dir: PathBuf,
+    /// The port being served, the only one requests may name in their `Host`.
+    port: u16,
+    results: Mutex<Vec<RunResult>>,
+    running: Mutex<HashSet<u32>>,
+}
+
+impl State {
+    fn new(days: &'static [Day], data_dir: &Path, port: u16) -> Self {
+        State {
+            days,
+            data_dir: data_dir.to_path_buf(),
+            port,
+            results: Mutex::default(),
+            running: Mutex::default(),
+        }
+    }
+
+    fn find(&self, day: &str) -> Option<&'static Day> {
+        let day = day.parse::<u32>().ok()?;
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+struct Response {
+    status: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Response {
+    fn html(body: String) -> Self {
+        Response {
+            status: "200 OK",
+            headers: vec![("Content-Type", "text/html; charset=utf-8".to_owned())],
+            body,
+        }
+    }
+
+    fn redirect(location: &str) -> Self {
+        Response {
+            status: "303 See Other",
+            headers: vec![("Location", location.to_owned())],
+            body: String::new(),
+        }
+    }
+
+    fn forbidden(reason: &str) -> Self {
+        Response {
+            status: "403 Forbidden",
+            headers: vec![("Content-Type", "text/plain".to_owned())],
+            body: reason.to_owned(),
+        }
+    }
+
+    fn not_found() -> Self {
+        Response {
+            status: "404 Not Found",
+            headers: vec![("Content-Type", "text/plain".to_owned())],
+            body: "not found".to_owned(),
+        }
+    }
+}
+
+fn handle(state: &Arc<State>, mut stream: TcpStream) -> Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut headers = Vec::new();
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header.trim_end() != "" {
+        if let Some((name, value)) = header.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+        }
+        header.clear();
+    }
+
+    let mut words = request_line.split_whitespace();
+    let method = words.next().unwrap_or_default();
+    let path = words.next().unwrap_or_default();
+    let response = match check(state.port, method, &headers) {
+        Ok(()) => route(state, method, path),
+        Err(reason) => Response::forbidden(reason),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    )?;
+    for (name, value) in &response.headers {
+        write!(stream, "{}: {}\r\n", name, value)?;
+    }
+    write!(stream, "\r\n{}", response.body)?;
+    Ok(())
+}
+
+/// Rejects requests that aren't addressed to this server by name, which a page on another site
+/// could make through DNS rebinding, and requests that change anything from another origin.
+fn check(port: u16, method: &str, headers: &[(String, String)]) -> Result<(), &'static str> {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.to_ascii_lowercase())
+    };
+    let hosts = [format!("127.0.0.1:{}", port), format!("localhost:{}", port)];
+    if !header("host").is_some_and(|host| hosts.contains(&host)) {
+        return Err("unexpected Host");
+    }
+    if method != "GET" {
+        let same_origin = header("origin").is_none_or(|origin| {
+            hosts
+                .iter()
+                .any(|host| origin == format!("http://{}", host))
+        });
+        let same_site =
+            header("sec-fetch-site").is_none_or(|site| site == "same-origin" || site == "none");
+        if !same_origin || !same_site {
+            return Err("cross-origin request");
+        }
+    }
+    Ok(())
+}
+
+fn route(state: &Arc<State>, method: &str, path: &str) -> Response {
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    match (method, segments.as_slice()) {
+        ("GET", []) => Response::html(dashboard(state)),
+        ("POST", ["run"]) => {
+            start_run(state, state.days.iter().collect());
+            Response::redirect("/")
+        }
+        ("POST", ["run", day]) => match state.find(day) {
+            Some(day) => {
+                start_run(state, vec![day]);
+                Response::redirect("/")
+            }
+            None => Response::not_found(),
+        },
+        ("GET", ["day", day]) => match state.find(day) {
+            Some(day) if day.visualize.is_some() => {
+                Response::html(visualization(day, &state.data_dir))
+            }
+            _ => Response::not_found(),
+        },
+        _ => Response::not_found(),
+    }
+}
+
+/// Re-runs `days` in the background, skipping any that are already running.
+fn start_run(state: &Arc<State>, days: Vec<&'static Day>) {
+    let days = {
+        let mut running = state.running.lock().unwrap();
+        days.into_iter()
+            .filter(|day| running.insert(day.day))
+            .collect::<Vec<_>>()
+    };
+    if days.is_empty() {
+        return;
+    }
+    let state = Arc::clone(state);
+    thread::spawn(move || {
+        let options = Options {
+            progress: false,
+            cache: false,
+            data_dir: state.data_dir.clone(),
+            ..Options::default()
+        };
+        let fresh = runner::run(&days, &options);
+        let mut results = state.results.lock().unwrap();
+        results.retain(|r| !days.iter().any(|day| day.day == r.day));
+        results.extend(fresh);
+        results.sort_by_key(|r| (r.day, r.part));
+        let mut running = state.running.lock().unwrap();
+        days.iter().for_each(|day| {
+            running.remove(&day.day);
+        });
+    });
+}
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em}\
+table{border-collapse:collapse}td,th{padding:.3em .8em;text-align:left;border-bottom:1px solid #ddd}\
+.ok{color:#080}.fail{color:#c00}pre{font-size:11px;line-height:1}form{display:inline}";
+
+fn dashboard(state: &State) -> String {
+    let results = state.results.lock().unwrap();
+    let running = state.running.lock().unwrap();
+    let year = state.days.first().map_or(0, |d| d.year);
+    let mut html = String::new();
+    if !running.is_empty() {
+        html += r#"<meta http-equiv="refresh" content="2">"#;
+    }
+    let _ = write!(
+        html,
+        "<title>Advent of Code {0}</title><style>{1}</style><h1>Advent of Code {0}</h1>\
+         <form method=post action=/run><button>Run all</button></form>\
+         <table><tr><th>day</th><th>part</th><th>implementation</th><th>time</th><th>status</th><th>answer</th><th></th></tr>",
+        year, STYLE
+    );
+    for day in state.days {
+        let links = format!(
+            "<form method=post action=/run/{}><button>{}</button></form>{}",
+            day.day,
+            if running.contains(&day.day) {
+                "running…"
+            } else {
+                "Run"
+            },
+            if day.visualize.is_some() {
+                format!(" <a href=/day/{}>visualize</a>", day.day)
+            } else {
+                String::new()
+            }
+        );
+        for (i, part) in day.parts.iter().enumerate() {
+            let result = results
+                .iter()
+                .find(|r| r.day == day.day && r.part == part.part && r.name == part.name);
+            let (time, class, status, answer) = match result {
+                None => (String::new(), "pending", "not run", String::new()),
+                Some(r) => {
+                    let (class, status, answer) = match &r.outcome {
+                        Outcome::Answer(answer) => ("ok", "ok", answer.to_string()),
+                        Outcome::Error(e) => ("fail", "error", e.clone()),
+                        Outcome::Panic(e) => ("fail", "panic", e.clone()),
+                        Outcome::Timeout => ("fail", "timeout", String::new()),
+                    };
+                    (format!("{:.2?}", r.elapsed), class, status, answer)
+                }
+            };
+            let _ = write!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class={}>{}</td><td>{}</td><td>{}</td></tr>",
+                if i == 0 {
+                    day_name(day.day)
+                } else {
+                    String::new()
+                },
+                part.part,
+                part.name,
+                time,
+                class,
+                status,
+                escape(&answer),
+                if i == 0 { links.as_str() } else { "" }
+            );
+        }
+    }
+    html += "</table>";
+    html
+}
+
+fn visualization(day: &Day, data_dir: &Path) -> String {
+    let name = format!("{} {}", day.year, day_name(day.day));
+    let picture =
+        read_input(data_dir, day.year, day.day).and_then(|input| (day.visualize.unwrap())(&input));
+    let body = match picture {
+        Ok(picture) => format!("<pre>{}</pre>", escape(&picture)),
+        Err(e) => format!("<p class=fail>{}</p>", escape(&format!("{:#}", e))),
+    };
+    format!(
+        "<title>{0}</title><style>{1}</style><p><a href=/>back</a></p><h1>{0}</h1>{2}",
+        name, STYLE, body
+    )
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use std::io::Read;
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn routes() {
+        let state = Arc::new(State::new(&days::y2024::DAYS, Path::new("data"), 8024));
+        assert_eq!(route(&state, "GET", "/").status, "200 OK");
+        assert_eq!(route(&state, "GET", "/day/3").status, "404 Not Found");
+        assert_eq!(route(&state, "POST", "/run/26").status, "404 Not Found");
+        assert_eq!(route(&state, "GET", "/nowhere").status, "404 Not Found");
+    }
+
+    #[test]
+    fn dashboard_shows_results() {
+        let state = State::new(&days::y2024::DAYS, Path::new("data"), 8024);
+        state.results.lock().unwrap().push(RunResult {
+            year: 2024,
+            day: 1,
+            part: 2,
+            name: "problem2_solution",
+            outcome: Outcome::Answer(Answer::Unsigned(31)),
+            elapsed: Duration::from_millis(3),
+            cached: false,
+        });
+        let html = dashboard(&state);
+        assert!(html.contains("<td class=ok>ok</td><td>31</td>"));
+        assert!(html.contains("<td class=pending>not run</td>"));
+        assert!(html.contains("<a href=/day/6>visualize</a>"));
+    }
+
+    #[test]
+    fn checks_host_and_origin() {
+        let headers = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let local = headers(&[("host", "localhost:8024")]);
+        assert_eq!(check(8024, "GET", &local), Ok(()));
+        assert_eq!(check(8024, "POST", &local), Ok(()));
+        assert_eq!(
+            check(8024, "GET", &headers(&[("host", "127.0.0.1:8024")])),
+            Ok(())
+        );
+        assert!(check(8024, "GET", &headers(&[("host", "evil.example")])).is_err());
+        assert!(check(8024, "GET", &headers(&[("host", "localhost:80")])).is_err());
+        assert!(check(8024, "GET", &[]).is_err());
+        let from = |origin, site| {
+            headers(&[
+                ("host", "localhost:8024"),
+                ("origin", origin),
+                ("sec-fetch-site", site),
+            ])
+        };
+        assert_eq!(
+            check(8024, "POST", &from("http://localhost:8024", "same-origin")),
+            Ok(())
+        );
+        assert!(check(8024, "POST", &from("http://evil.example", "cross-site")).is_err());
+        assert!(check(8024, "POST", &from("http://localhost:8024", "cross-site")).is_err());
+    }
+
+    #[test]
+    fn serves_over_loopback_despite_idle_clients() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(State::new(
+            &days::y2024::DAYS,
+            Path::new("data"),
+            addr.port(),
+        ));
+        thread::spawn(move || accept(listener, state));
+
+        let _idle = TcpStream::connect(addr).unwrap();
+        let request = |host: &str| {
+            let mut client = TcpStream::connect(addr).unwrap();
+            client.set_read_timeout(Some(READ_TIMEOUT / 2)).unwrap();
+            write!(client, "GET / HTTP/1.1\r\nHost: {}\r\n\r\n", host).unwrap();
+            let mut response = String::new();
+            client.read_to_string(&mut response).unwrap();
+            response
+        };
+        let response = request(&format!("localhost:{}", addr.port()));
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Advent of Code 2024"));
+        assert!(request("evil.example").starts_with("HTTP/1.1 403 Forbidden\r\n"));
+    }
+}
@@ -0,0 +1,192 @@
+use nalgebra::Vector2;
+use std::fmt::{self, Write as _};
+
+/// An SVG drawing in grid coordinates: cell (x, y) covers the unit square from (x, y) to
+/// (x + 1, y + 1), so cell centres are at half-integer coordinates.
+pub struct Svg {
+    columns: usize,
+    rows: usize,
+    scale: f64,
+    elements: String,
+}
+
+impl Svg {
+    /// Starts a dark drawing of `columns`×`rows` cells, each `scale` pixels wide.
+    pub fn new(columns: usize, rows: usize, scale: f64) -> Self {
+        let mut svg = Svg {
+            columns,
+            rows,
+            scale,
+            elements: String::new(),
+        };
+        let _ = writeln!(
+            svg.elements,
+            r##"<rect width="{}" height="{}" fill="#10101c"/>"##,
+            svg.px(columns as f64),
+            svg.px(rows as f64)
+        );
+        svg
+    }
+
+    /// The centre of cell `p`.
+    pub fn centre(p: Vector2<isize>) -> Vector2<f64> {
+        p.cast::<f64>().add_scalar(0.5)
+    }
+
+    /// Draws the lines between cells.
+    pub fn grid(&mut self, stroke: &str) -> &mut Self {
+        let (width, height) = (self.columns as f64, self.rows as f64);
+        for x in 0..=self.columns {
+            let x = x as f64;
+            self.line(Vector2::new(x, 0.0), Vector2::new(x, height), stroke, 0.02);
+        }
+        for y in 0..=self.rows {
+            let y = y as f64;
+            self.line(Vector2::new(0.0, y), Vector2::new(width, y), stroke, 0.02);
+        }
+        self
+    }
+
+    /// Fills cell `p`, writing `label` in the middle of it if given.
+    pub fn cell(&mut self, p: Vector2<isize>, fill: &str, label: Option<&str>) -> &mut Self {
+        let corner = p.cast::<f64>();
+        let _ = writeln!(
+            self.elements,
+            r#"<rect x="{}" y="{}" width="{2}" height="{2}" fill="{3}"/>"#,
+            self.px(corner.x),
+            self.px(corner.y),
+            self.px(1.0),
+            fill
+        );
+        if let Some(label) = label {
+            let centre = Self::centre(p);
+            let _ = writeln!(
+                self.elements,
+                r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central" fill="white">{}</text>"#,
+                self.px(centre.x),
+                self.px(centre.y),
+                self.px(0.7),
+                escape(label)
+            );
+        }
+        self
+    }
+
+    pub fn point(&mut self, p: Vector2<f64>, radius: f64, fill: &str) -> &mut Self {
+        let _ = writeln!(
+            self.elements,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            self.px(p.x),
+            self.px(p.y),
+            self.px(radius),
+            fill
+        );
+        self
+    }
+
+    pub fn line(
+        &mut self,
+        a: Vector2<f64>,
+        b: Vector2<f64>,
+        stroke: &str,
+        width: f64,
+    ) -> &mut Self {
+        let _ = writeln!(
+            self.elements,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+            self.px(a.x),
+            self.px(a.y),
+            self.px(b.x),
+            self.px(b.y),
+            stroke,
+            self.px(width)
+        );
+        self
+    }
+
+    pub fn polyline(&mut self, points: &[Vector2<f64>], stroke: &str, width: f64) -> &mut Self {
+        let points = points
+            .iter()
+            .map(|p| format!("{},{}", self.px(p.x), self.px(p.y)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(
+            self.elements,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            points,
+            stroke,
+            self.px(width)
+        );
+        self
+    }
+
+    fn px(&self, v: f64) -> f64 {
+        (v * self.scale * 100.0).round() / 100.0
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (self.px(self.columns as f64), self.px(self.rows as f64));
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        )?;
+        write!(f, "{}</svg>", self.elements)
+    }
+}
+
+/// The `i`th of a sequence of colours whose hues are spread by the golden angle, so that
+/// neighbouring indices are easy to tell apart.
+pub fn colour(i: usize) -> String {
+    format!("hsl({},75%,60%)", (i * 137) % 360)
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_in_grid_coordinates() {
+        let mut svg = Svg::new(2, 1, 10.0);
+        svg.cell(Vector2::new(1, 0), "red", Some("<a>"))
+            .point(Svg::centre(Vector2::new(0, 0)), 0.25, "blue")
+            .polyline(
+                &[Vector2::new(0.5, 0.5), Vector2::new(1.5, 0.5)],
+                "green",
+                0.1,
+            );
+        assert_eq!(
+            svg.to_string(),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10" viewBox="0 0 20 10">
+<rect width="20" height="10" fill="#10101c"/>
+<rect x="10" y="0" width="10" height="10" fill="red"/>
+<text x="15" y="5" font-size="7" text-anchor="middle" dominant-baseline="central" fill="white">&lt;a&gt;</text>
+<circle cx="5" cy="5" r="2.5" fill="blue"/>
+<polyline points="5,5 15,5" fill="none" stroke="green" stroke-width="1" stroke-linejoin="round"/>
+</svg>"##
+        );
+    }
+
+    #[test]
+    fn grid_lines() {
+        let mut svg = Svg::new(3, 2, 1.0);
+        svg.grid("grey");
+        assert_eq!(svg.to_string().matches("<line").count(), 4 + 3);
+    }
+
+    #[test]
+    fn colours_are_distinct() {
+        let colours = (0..62)
+            .map(colour)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(colours.len(), 62);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::answer::Answer;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::hash_map::RandomState,
+    fmt::Write as _,
+    fs,
+    hash::BuildHasher,
+    path::{Path, PathBuf},
+};
+
+/// Slow salted hashes of the known answers, so that they can't be read off at a glance. Answers
+/// are small numbers, so each hash slows down but cannot prevent guessing them all.
+pub const ANSWERS_FILE: &str = "answers.json";
+/// The plain answers, kept out of git, for showing what was expected after a mismatch.
+pub const LOCAL_ANSWERS_FILE: &str = "answers.local.json";
+
+/// How many times newly recorded answers are hashed, making each guess cost a noticeable
+/// fraction of a second.
+const ITERATIONS: u32 = 1_000_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Hashed {
+    year: u32,
+    day: u32,
+    part: u32,
+    salt: String,
+    iterations: u32,
+    hash: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Plain {
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: Answer,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Match,
+    /// The expected answer is only known when the local answers file has it.
+    Mismatch {
+        expected: Option<Answer>,
+    },
+    Unknown,
+}
+
+/// The known answer for each part, checked by comparing salted hashes.
+pub struct Answers {
+    dir: PathBuf,
+    hashed: Vec<Hashed>,
+    plain: Vec<Plain>,
+    /// How many times `record` hashes answers.
+    iterations: u32,
+}
+
+impl Answers {
+    /// Loads the answer files in `dir`, either of which may be missing.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Answers> {
+        let dir = dir.as_ref().to_path_buf();
+        Ok(Answers {
+            hashed: read(&dir.join(ANSWERS_FILE))?,
+            plain: read(&dir.join(LOCAL_ANSWERS_FILE))?,
+            dir,
+            iterations: ITERATIONS,
+        })
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &Answer) -> Verdict {
+        let key = (year, day, part);
+        let Some(hashed) = self.hashed.iter().find(|h| (h.year, h.day, h.part) == key) else {
+            return Verdict::Unknown;
+        };
+        if stretched_hash(&hashed.salt, hashed.iterations, answer) == hashed.hash {
+            return Verdict::Match;
+        }
+        let expected = self
+            .plain
+            .iter()
+            .find(|p| (p.year, p.day, p.part) == key)
+            .map(|p| p.answer.clone());
+        Verdict::Mismatch { expected }
+    }
+
+    /// Records `answer` as the known answer, replacing any earlier one.
+    pub fn record(&mut self, year: u32, day: u32, part: u32, answer: &Answer) {
+        let key = (year, day, part);
+        self.hashed.retain(|h| (h.year, h.day, h.part) != key);
+        self.plain.retain(|p| (p.year, p.day, p.part) != key);
+        let salt = salt();
+        self.hashed.push(Hashed {
+            year,
+            day,
+            part,
+            hash: stretched_hash(&salt, self.iterations, answer),
+            salt,
+            iterations: self.iterations,
+        });
+        self.plain.push(Plain {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+        });
+        self.hashed.sort_by_key(|h| (h.year, h.day, h.part));
+        self.plain.sort_by_key(|p| (p.year, p.day, p.part));
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(
+            self.dir.join(ANSWERS_FILE),
+            serde_json::to_string_pretty(&self.hashed)? + "\n",
+        )?;
+        fs::write(
+            self.dir.join(LOCAL_ANSWERS_FILE),
+            serde_json::to_string_pretty(&self.plain)? + "\n",
+        )?;
+        Ok(())
+    }
+}
+
+fn read<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let json = fs::read_to_string(path)?;
+    serde_json::from_str(&json).with_context(|| format!("invalid answers in {}", path.display()))
+}
+
+/// SHA-256 of the salt and the answer as printed, hashed again `iterations - 1` times, in hex.
+fn stretched_hash(salt: &str, iterations: u32, answer: &Answer) -> String {
+    let mut digest = Sha256::new()
+        .chain_update(salt)
+        .chain_update(answer.to_string())
+        .finalize();
+    for _ in 1..iterations {
+        digest = Sha256::digest(digest);
+    }
+    digest.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{:02x}", b);
+        hex
+    })
+}
+
+/// 128 random bits, taken from the randomly keyed hashers the standard library seeds from the OS.
+fn salt() -> String {
+    let random = || RandomState::new().hash_one(0u8);
+    format!("{:016x}{:016x}", random(), random())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers() -> Answers {
+        Answers {
+            dir: PathBuf::new(),
+            hashed: Vec::new(),
+            plain: Vec::new(),
+            iterations: 1000,
+        }
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(
+            stretched_hash("", 1, &Answer::from("abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // SHA-256 of the digest above.
+        assert_eq!(
+            stretched_hash("", 2, &Answer::from("abc")),
+            "4f8b42c22dd3729b519ba6f68d2da7cc5b2d606d05daed5ad5128cc03e6c6358"
+        );
+        assert_ne!(salt(), salt());
+    }
+
+    #[test]
+    fn verdicts() {
+        let mut answers = answers();
+        answers.record(2024, 1, 2, &Answer::Unsigned(31));
+        assert_eq!(
+            answers.check(2024, 1, 2, &Answer::Unsigned(31)),
+            Verdict::Match
+        );
+        assert_eq!(
+            answers.check(2024, 1, 1, &Answer::Unsigned(31)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(2024, 1, 2, &Answer::Unsigned(32)),
+            Verdict::Mismatch {
+                expected: Some(Answer::Unsigned(31))
+            }
+        );
+
+        answers.plain.clear();
+        assert_eq!(
+            answers.check(2024, 1, 2, &Answer::Unsigned(32)),
+            Verdict::Mismatch { expected: None }
+        );
+    }
+
+    #[test]
+    fn committed_file_has_no_plain_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut answers = Answers::load(&dir).unwrap();
+        answers.iterations = 1000;
+        answers.record(2024, 6, 1, &Answer::Unsigned(5318));
+        answers.save().unwrap();
+
+        let committed = fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap();
+        let loaded = Answers::load(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let entry: serde_json::Value = serde_json::from_str(&committed).unwrap();
+        let keys = entry[0].as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["day", "hash", "iterations", "part", "salt", "year"]);
+        assert_eq!(entry[0]["iterations"], 1000);
+        assert_eq!(
+            loaded.check(2024, 6, 1, &Answer::Unsigned(5318)),
+            Verdict::Match
+        );
+    }
+}
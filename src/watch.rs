@@ -0,0 +1,205 @@
+use crate::{day_name, input_path};
+use anyhow::{anyhow, Result};
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls a day's source and input files, re-running its tests and solution whenever either changes.
+pub fn watch(data_dir: &Path, year: u32, day: u32) -> Result<()> {
+    let name = day_name(day);
+    let paths = [
+        PathBuf::from(format!("src/days/y{}/{}.rs", year, name)),
+        input_path(data_dir, year, day),
+    ];
+    if !paths[0].exists() {
+        return Err(anyhow!("{} does not exist", paths[0].display()));
+    }
+    println!(
+        "watching {} (Ctrl-C to stop)",
+        paths
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut last = None;
+    loop {
+        let stamps = paths.iter().map(modified).collect::<Vec<_>>();
+        if last.as_ref() != Some(&stamps) {
+            run_once(year, &name)?;
+            last = Some(stamps);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn run_once(year: u32, name: &str) -> Result<()> {
+    let start = Instant::now();
+    println!("---- {} {} ----", year, name);
+
+    let filter = format!("days::y{}::{}::", year, name);
+    let test = cargo(&["test", "--quiet", "--lib", "--", &filter])?;
+    let stdout = String::from_utf8_lossy(&test.stdout);
+    match parse_test_summary(&stdout) {
+        Some(summary) if summary.failed == 0 => {
+            println!("tests    PASS {}/{}", summary.passed, summary.total())
+        }
+        Some(summary) => {
+            println!("tests    FAIL {}/{}", summary.passed, summary.total());
+            for failure in summary.failures {
+                println!("  failed {}", failure);
+            }
+        }
+        None => {
+            println!("tests    BUILD FAILED");
+            print_errors(&String::from_utf8_lossy(&test.stderr));
+            return Ok(());
+        }
+    }
+
+    let run = cargo(&["run", "--quiet", "--bin", &format!("y{}_{}", year, name)])?;
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    for (part, answer) in parse_answers(&stdout) {
+        println!("{} = {}", part, answer);
+    }
+    if !run.status.success() {
+        println!("run      FAIL ({})", run.status);
+        print_errors(&String::from_utf8_lossy(&run.stderr));
+    }
+    println!("({:.2?})", start.elapsed());
+    Ok(())
+}
+
+fn cargo(args: &[&str]) -> Result<std::process::Output> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Ok(Command::new(cargo).args(args).output()?)
+}
+
+fn print_errors(stderr: &str) {
+    stderr
+        .lines()
+        .filter(|line| line.starts_with("error") || line.starts_with("Error"))
+        .for_each(|line| println!("  {}", line));
+}
+
+#[derive(Debug, PartialEq)]
+struct TestSummary {
+    passed: usize,
+    failed: usize,
+    failures: Vec<String>,
+}
+
+impl TestSummary {
+    fn total(&self) -> usize {
+        self.passed + self.failed
+    }
+}
+
+/// Reads the `test result:` line and the trailing list of failed tests from `cargo test` output.
+fn parse_test_summary(stdout: &str) -> Option<TestSummary> {
+    let result = stdout
+        .lines()
+        .find(|line| line.starts_with("test result:"))?;
+    let count = |label: &str| {
+        result
+            .split(';')
+            .find_map(|field| field.trim().strip_suffix(label))
+            .and_then(|n| n.rsplit(' ').next())
+            .and_then(|n| n.trim().parse::<usize>().ok())
+            .unwrap_or_default()
+    };
+    let failures = stdout
+        .rsplit_once("\nfailures:\n")
+        .map(|(_, tail)| {
+            tail.lines()
+                .take_while(|line| !line.is_empty())
+                .map(|line| line.trim().to_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(TestSummary {
+        passed: count(" passed"),
+        failed: count(" failed"),
+        failures,
+    })
+}
+
+fn parse_answers(stdout: &str) -> Vec<(&str, &str)> {
+    stdout
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSING: &str = "
+running 2 tests
+..
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    const FAILING: &str = "
+running 2 tests
+.F
+failures:
+
+---- tests::problem2 stdout ----
+
+thread 'tests::problem2' panicked at src/days/day01.rs:81:9:
+assertion `left == right` failed
+
+failures:
+    tests::problem2
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn passing_summary() {
+        let summary = parse_test_summary(PASSING).unwrap();
+        assert_eq!(
+            summary,
+            TestSummary {
+                passed: 2,
+                failed: 0,
+                failures: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn failing_summary() {
+        let summary = parse_test_summary(FAILING).unwrap();
+        assert_eq!(
+            summary,
+            TestSummary {
+                passed: 1,
+                failed: 1,
+                failures: vec!["tests::problem2".to_owned()]
+            }
+        );
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(
+            parse_answers("problem1 = 11\nproblem2 = 31\n"),
+            vec![("problem1", "11"), ("problem2", "31")]
+        );
+    }
+}